@@ -0,0 +1,12 @@
+mod analytics;
+mod word_difficulty;
+
+pub use analytics::{
+  Analytics,
+  GameSummary,
+  SetSummary,
+};
+pub use word_difficulty::{
+  WordDifficulty,
+  WordStats,
+};
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod test;
+
+use std::hash::Hash;
+
+use crate::{
+  analytics::WordDifficulty,
+  coder::UnseenSetID,
+  game_over::{
+    GameOver,
+    Stats,
+  },
+};
+
+// -------------------------------------------------------------------------------------------------
+// Summaries
+// -------------------------------------------------------------------------------------------------
+
+/// A game in an `Analytics`.
+#[derive(Clone, Debug)]
+pub struct GameSummary
+{
+  timestamp: u64,
+  unseen_set_id: UnseenSetID,
+  score: usize,
+  stats: Stats,
+}
+
+impl GameSummary
+{
+  pub fn timestamp(&self) -> u64
+  {
+    self.timestamp
+  }
+
+  pub fn unseen_set_id(&self) -> &UnseenSetID
+  {
+    &self.unseen_set_id
+  }
+
+  pub fn score(&self) -> usize
+  {
+    self.score
+  }
+
+  pub fn stats(&self) -> &Stats
+  {
+    &self.stats
+  }
+}
+
+/// The scores of the games played with an `UnseenSetID`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetSummary
+{
+  pub unseen_set_id: UnseenSetID,
+  pub games: usize,
+  pub best: usize,
+  pub mean: f64,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Analytics
+// -------------------------------------------------------------------------------------------------
+
+/// Aggregated statistics over the games of a player.
+#[derive(Clone, Debug)]
+pub struct Analytics<T>
+{
+  // Ordered by timestamp.
+  games: Vec<GameSummary>,
+  // The amount of incorrect commits at each commit index.
+  error_positions: Vec<usize>,
+  words: WordDifficulty<T>,
+}
+
+impl<T> Default for Analytics<T>
+{
+  fn default() -> Self
+  {
+    Analytics {
+      games: Vec::new(),
+      error_positions: Vec::new(),
+      words: WordDifficulty::default(),
+    }
+  }
+}
+
+impl<T> Analytics<T>
+where
+  T: Clone + Eq + Hash + AsRef<[u8]>,
+{
+  /// Adds a game played at `timestamp`. Games can be added in any order.
+  pub fn add(&mut self, timestamp: u64, game_over: &GameOver<T>)
+  {
+    let i = self.games.partition_point(|x| x.timestamp <= timestamp);
+    self.games.insert(
+      i,
+      GameSummary {
+        timestamp,
+        unseen_set_id: game_over.unseen_set_id().clone(),
+        score: game_over.score(),
+        stats: game_over.stats(),
+      },
+    );
+
+    for &j in game_over.incorrect_commits().iter().flatten() {
+      if self.error_positions.len() <= j {
+        self.error_positions.resize(j + 1, 0);
+      }
+      self.error_positions[j] += 1;
+    }
+
+    self.words.add(game_over);
+  }
+}
+
+impl<T> Analytics<T>
+{
+  /// The games ordered by timestamp.
+  pub fn games(&self) -> &[GameSummary]
+  {
+    &self.games
+  }
+
+  /// The best and mean score of each `UnseenSetID`, in the order the sets were first played.
+  pub fn sets(&self) -> Vec<SetSummary>
+  {
+    let mut sets: Vec<SetSummary> = Vec::new();
+    for game in &self.games {
+      match sets
+        .iter_mut()
+        .find(|x| x.unseen_set_id == game.unseen_set_id)
+      {
+        Some(set) => {
+          set.mean += (game.score as f64 - set.mean) / (set.games + 1) as f64;
+          set.games += 1;
+          set.best = set.best.max(game.score);
+        }
+        None => sets.push(SetSummary {
+          unseen_set_id: game.unseen_set_id.clone(),
+          games: 1,
+          best: game.score,
+          mean: game.score as f64,
+        }),
+      }
+    }
+    sets
+  }
+
+  /// For each game, the mean score of it and the `window - 1` games before it.
+  pub fn rolling_average(&self, window: usize) -> Vec<f64>
+  {
+    let window = window.max(1);
+    let mut sum = 0;
+    self
+      .games
+      .iter()
+      .enumerate()
+      .map(|(i, game)| {
+        sum += game.score;
+        if i >= window {
+          sum -= self.games[i - window].score;
+        }
+        sum as f64 / window.min(i + 1) as f64
+      })
+      .collect()
+  }
+
+  /// The d′ of each game. See `Stats::d_prime`.
+  pub fn d_prime_trend(&self) -> Vec<Option<f64>>
+  {
+    self.games.iter().map(|x| x.stats.d_prime()).collect()
+  }
+
+  /// The amount of incorrect commits at each commit index over every game.
+  pub fn error_positions(&self) -> &[usize]
+  {
+    &self.error_positions
+  }
+
+  /// The words the player misjudged.
+  pub fn words(&self) -> &WordDifficulty<T>
+  {
+    &self.words
+  }
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+fn game_over(seed: u64, unseen_set_id: UnseenSetID, incorrect: [usize; 3]) -> GameOver<String>
+{
+  GameOver::new(
+    seed,
+    unseen_set_id,
+    (0..64).map(|i| format!("w{}", i)).collect(),
+    0.4.try_into().unwrap(),
+    incorrect.map(Some).to_vec(),
+  )
+  .unwrap()
+}
+
+#[test]
+fn aggregate_games()
+{
+  let mut analytics = Analytics::default();
+  analytics.add(30, &game_over(1, UnseenSetID::Unspecified, [2, 4, 6]));
+  analytics.add(10, &game_over(2, UnseenSetID::Unspecified, [1, 2, 3]));
+  analytics.add(20, &game_over(3, UnseenSetID::DictionaryFr01, [2, 9, 10]));
+
+  assert_eq!(
+    analytics
+      .games()
+      .iter()
+      .map(|x| x.score())
+      .collect::<Vec<_>>(),
+    [1, 8, 4]
+  );
+  assert_eq!(
+    analytics.sets(),
+    [
+      SetSummary {
+        unseen_set_id: UnseenSetID::Unspecified,
+        games: 2,
+        best: 4,
+        mean: 2.5,
+      },
+      SetSummary {
+        unseen_set_id: UnseenSetID::DictionaryFr01,
+        games: 1,
+        best: 8,
+        mean: 8.0,
+      },
+    ]
+  );
+  assert_eq!(analytics.rolling_average(2), [1.0, 4.5, 6.0]);
+  assert_eq!(
+    analytics.error_positions(),
+    [0, 1, 3, 1, 1, 0, 1, 0, 0, 1, 1]
+  );
+  assert_eq!(analytics.d_prime_trend().len(), 3);
+}
+
+#[test]
+fn hardest_words_first()
+{
+  let mut analytics = Analytics::default();
+  for seed in 0..16 {
+    analytics.add(seed, &game_over(seed, UnseenSetID::Unspecified, [0, 5, 7]));
+  }
+
+  let ranked = analytics.words().ranked(1);
+  assert!(ranked
+    .windows(2)
+    .all(|w| w[0].1.error_rate() >= w[1].1.error_rate()));
+  let errors: usize = ranked
+    .iter()
+    .map(|(_, x)| x.misses() + x.false_alarms())
+    .sum();
+  assert_eq!(errors, 16 * 3);
+}
@@ -0,0 +1,158 @@
+use std::{
+  collections::HashMap,
+  hash::Hash,
+};
+
+use crate::game_over::{
+  Commit,
+  GameOver,
+  SeenUnseen,
+};
+
+// -------------------------------------------------------------------------------------------------
+// WordStats
+// -------------------------------------------------------------------------------------------------
+
+/// How a word was judged over many commits.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WordStats
+{
+  seen: usize,
+  unseen: usize,
+  misses: usize,
+  false_alarms: usize,
+}
+
+impl WordStats
+{
+  /// Adds a commit of the word.
+  pub fn record<T>(&mut self, commit: &Commit<T>)
+  {
+    match (commit.actual(), commit.guess()) {
+      (SeenUnseen::Seen, SeenUnseen::Seen) => self.seen += 1,
+      (SeenUnseen::Seen, SeenUnseen::Unseen) => {
+        self.seen += 1;
+        self.misses += 1;
+      }
+      (SeenUnseen::Unseen, SeenUnseen::Seen) => {
+        self.unseen += 1;
+        self.false_alarms += 1;
+      }
+      (SeenUnseen::Unseen, SeenUnseen::Unseen) => self.unseen += 1,
+    }
+  }
+
+  /// Adds the commits of `other`.
+  pub fn merge(&mut self, other: &WordStats)
+  {
+    self.seen += other.seen;
+    self.unseen += other.unseen;
+    self.misses += other.misses;
+    self.false_alarms += other.false_alarms;
+  }
+
+  /// Amount of commits of the word.
+  pub fn commits(&self) -> usize
+  {
+    self.seen + self.unseen
+  }
+
+  /// Times the word was seen and guessed as unseen.
+  pub fn misses(&self) -> usize
+  {
+    self.misses
+  }
+
+  /// Times the word was unseen and guessed as seen.
+  pub fn false_alarms(&self) -> usize
+  {
+    self.false_alarms
+  }
+
+  /// The ratio of the seen commits that were misses.
+  pub fn miss_rate(&self) -> Option<f64>
+  {
+    (self.seen > 0).then(|| self.misses as f64 / self.seen as f64)
+  }
+
+  /// The ratio of the unseen commits that were false alarms.
+  pub fn false_alarm_rate(&self) -> Option<f64>
+  {
+    (self.unseen > 0).then(|| self.false_alarms as f64 / self.unseen as f64)
+  }
+
+  /// The ratio of the commits that were incorrect.
+  pub fn error_rate(&self) -> f64
+  {
+    (self.misses + self.false_alarms) as f64 / self.commits().max(1) as f64
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// WordDifficulty
+// -------------------------------------------------------------------------------------------------
+
+/// `WordStats` of every word in a collection of games. Commits that are a choice between two
+/// elements are not included.
+#[derive(Clone, Debug)]
+pub struct WordDifficulty<T>(HashMap<T, WordStats>);
+
+impl<T> Default for WordDifficulty<T>
+{
+  fn default() -> Self
+  {
+    WordDifficulty(HashMap::new())
+  }
+}
+
+impl<T> WordDifficulty<T>
+where
+  T: Clone + Eq + Hash,
+{
+  /// Adds the commits of a game.
+  pub fn add(&mut self, game_over: &GameOver<T>)
+  where
+    T: AsRef<[u8]>,
+  {
+    for commit in game_over.iter().filter(|x| x.alternative().is_none()) {
+      self
+        .0
+        .entry(commit.element().clone())
+        .or_default()
+        .record(&commit);
+    }
+  }
+
+  /// Adds the words of `other`.
+  pub fn merge(&mut self, other: &WordDifficulty<T>)
+  {
+    for (word, stats) in &other.0 {
+      self.0.entry(word.clone()).or_default().merge(stats);
+    }
+  }
+
+  pub fn get(&self, word: &T) -> Option<&WordStats>
+  {
+    self.0.get(word)
+  }
+
+  /// The words with at least `min_commits` commits, hardest first. Words are ordered by error rate,
+  /// then by amount of errors.
+  pub fn ranked(&self, min_commits: usize) -> Vec<(&T, &WordStats)>
+  where
+    T: Ord,
+  {
+    let mut ranked: Vec<(&T, &WordStats)> = self
+      .0
+      .iter()
+      .filter(|(_, x)| x.commits() >= min_commits.max(1))
+      .collect();
+    ranked.sort_by(|(a, x), (b, y)| {
+      y.error_rate()
+        .total_cmp(&x.error_rate())
+        .then((y.misses + y.false_alarms).cmp(&(x.misses + x.false_alarms)))
+        .then(a.cmp(b))
+    });
+    ranked
+  }
+}
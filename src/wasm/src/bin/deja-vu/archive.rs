@@ -0,0 +1,84 @@
+use std::{
+  error::Error,
+  fs::{
+    self,
+    File,
+  },
+  io::{
+    self,
+    BufReader,
+    Read,
+    Write,
+  },
+};
+
+use deja_vu_wasm::{
+  coder::{
+    ArchiveHeader,
+    ArchiveReader,
+    ArchiveWriter,
+    SealedEncodedGameOver,
+  },
+  history::Record,
+};
+
+use crate::args::Args;
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  match args.get("extract") {
+    Some(path) => extract(path),
+    None => pack(args),
+  }
+}
+
+/// Writes the share codes of the positional files, or stdin, as an archive to stdout.
+fn pack(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  let mut corpus = String::new();
+  if args.positional().is_empty() {
+    io::stdin().read_to_string(&mut corpus)?;
+  }
+  for path in args.positional() {
+    corpus += &fs::read_to_string(path)?;
+    corpus.push('\n');
+  }
+
+  let mut header = ArchiveHeader::new();
+  if let Some(title) = args.get("title") {
+    header = header.with_title(title);
+  }
+  let mut writer = ArchiveWriter::new(io::stdout().lock(), &header)?;
+  for (i, line) in corpus.lines().map(str::trim).enumerate() {
+    if line.is_empty() {
+      continue;
+    }
+    writer
+      .write(&parse(line)?)
+      .map_err(|e| format!("line {}: {}", i + 1, e))?;
+  }
+  writer.finish()?.flush()?;
+  Ok(())
+}
+
+/// Verifies the archive at `path` and prints the share query of every game.
+fn extract(path: &str) -> Result<(), Box<dyn Error>>
+{
+  let reader = ArchiveReader::new(BufReader::new(File::open(path)?))?;
+  if let Some(title) = reader.header().title() {
+    eprintln!("{}", title);
+  }
+  for sealed in reader {
+    println!("{}", serde_urlencoded::to_string(&sealed?)?);
+  }
+  Ok(())
+}
+
+fn parse(line: &str) -> Result<SealedEncodedGameOver, Box<dyn Error>>
+{
+  if line.starts_with('{') {
+    Ok(serde_json::from_str::<Record>(line)?.game().clone())
+  } else {
+    line.parse()
+  }
+}
@@ -0,0 +1,142 @@
+use std::{
+  collections::{
+    HashMap,
+    HashSet,
+  },
+  error::Error,
+  fs,
+  num::NonZeroUsize,
+  path::Path,
+  str::FromStr,
+};
+
+use deja_vu_wasm::{
+  coder::{
+    MacKey,
+    UnseenSetID,
+  },
+  game::GameMode,
+  words,
+};
+
+const DEFAULT_DICTIONARY: &str = "public/dictionary";
+
+/// Command line arguments on the form `--option value`, `--flag` and positional arguments.
+#[derive(Debug)]
+pub struct Args
+{
+  options: HashMap<String, String>,
+  flags: HashSet<String>,
+  positional: Vec<String>,
+}
+
+impl Args
+{
+  /// Parses `args`. Names in `flags` never take a value.
+  pub fn parse<I>(args: I, flags: &[&str]) -> Args
+  where
+    I: IntoIterator<Item = String>,
+  {
+    let mut options = HashMap::new();
+    let mut found = HashSet::new();
+    let mut positional = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+      match arg.strip_prefix("--") {
+        Some(name) if flags.contains(&name) => {
+          found.insert(name.to_string());
+        }
+        Some(name) => {
+          options.insert(name.to_string(), args.next().unwrap_or_default());
+        }
+        None => positional.push(arg),
+      }
+    }
+
+    Args {
+      options,
+      flags: found,
+      positional,
+    }
+  }
+
+  /// The value of an option.
+  pub fn get(&self, name: &str) -> Option<&str>
+  {
+    self.options.get(name).map(|x| x.as_str())
+  }
+
+  /// Parses the value of an option or returns `default` if it is missing.
+  pub fn parse_or<T>(&self, name: &str, default: T) -> Result<T, Box<dyn Error>>
+  where
+    T: FromStr,
+    T::Err: Error + 'static,
+  {
+    self.get(name).map_or(Ok(default), |x| {
+      x.parse().map_err(|e| format!("--{}: {}", name, e).into())
+    })
+  }
+
+  /// True if the flag is set.
+  pub fn flag(&self, name: &str) -> bool
+  {
+    self.flags.contains(name)
+  }
+
+  /// Loads the word list given by `--words` or else the word list of `unseen_set_id` from the
+  /// directory given by `--dictionary`.
+  pub fn words(&self, unseen_set_id: &UnseenSetID) -> Result<Vec<String>, Box<dyn Error>>
+  {
+    match self.get("words") {
+      Some(path) => words::load(Path::new(path)),
+      None => words::load_unseen_set(
+        Path::new(self.get("dictionary").unwrap_or(DEFAULT_DICTIONARY)),
+        unseen_set_id,
+      ),
+    }
+  }
+
+  /// Reads the hex encoded `MacKey` from the file given by `--key-file`, if any.
+  pub fn key(&self) -> Result<Option<MacKey>, Box<dyn Error>>
+  {
+    match self.get("key-file") {
+      Some(path) => Ok(Some(
+        fs::read_to_string(path)?
+          .parse()
+          .map_err(|e| format!("--key-file: {}", e))?,
+      )),
+      None => Ok(None),
+    }
+  }
+
+  /// The positional arguments.
+  pub fn positional(&self) -> &[String]
+  {
+    &self.positional
+  }
+}
+
+/// Parses a `GameMode` written as `classic`, `adaptive`, `window:<k>`, `n-back:<n>` or
+/// `forced-choice`.
+pub fn parse_mode(s: &str) -> Result<GameMode, Box<dyn Error>>
+{
+  let (name, value) = s.split_once(':').unwrap_or((s, ""));
+  match name {
+    "classic" => Ok(GameMode::Classic),
+    "adaptive" => Ok(GameMode::Adaptive),
+    "window" => Ok(GameMode::Window(mode_size(s, value)?)),
+    "n-back" => Ok(GameMode::NBack(mode_size(s, value)?)),
+    "forced-choice" => Ok(GameMode::ForcedChoice),
+    _ => Err(format!("mode '{}' is unrecognised", s).into()),
+  }
+}
+
+/// The size given after the name of a mode, which must be at least 1.
+fn mode_size(s: &str, value: &str) -> Result<usize, Box<dyn Error>>
+{
+  value
+    .parse::<NonZeroUsize>()
+    .map(NonZeroUsize::get)
+    .map_err(|_| format!("mode '{}' needs a size of at least 1", s).into())
+}
@@ -0,0 +1,95 @@
+use std::{
+  error::Error,
+  time::{
+    SystemTime,
+    UNIX_EPOCH,
+  },
+};
+
+use deja_vu_wasm::{
+  coder::{
+    Challenge,
+    ChallengeBuilder,
+    SealedChallenge,
+    SealedEncodedGameOver,
+    UnseenSetID,
+  },
+  game::INITIAL_LIVES_AMOUNT,
+};
+
+use crate::args::{
+  parse_mode,
+  Args,
+};
+
+/// The challenge described by the options of the game.
+pub fn from_args(args: &Args) -> Result<Challenge, Box<dyn Error>>
+{
+  let ratio: f64 = args.parse_or("ratio", 0.4)?;
+  Ok(
+    ChallengeBuilder::default()
+      .seed(args.parse_or(
+        "seed",
+        SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64,
+      )?)
+      .seen_threshold(ratio.try_into()?)
+      .unseen_set_id(args.parse_or("set", UnseenSetID::Top999WiktionaryFr)?)
+      .lives(args.parse_or("lives", INITIAL_LIVES_AMOUNT)?)
+      .mode(parse_mode(args.get("mode").unwrap_or("classic"))?)
+      .build()?,
+  )
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  match args.positional() {
+    [] => create(args),
+    [challenge, results @ ..] => compare(args, &challenge.parse()?, results),
+  }
+}
+
+/// Prints the link of a new challenge.
+fn create(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  let sealed = SealedChallenge::new(&from_args(args)?)?;
+  let query = serde_urlencoded::to_string(&sealed)?;
+  match args.get("url") {
+    Some(url) => println!("{}?{}", url, query),
+    None => println!("?{}", query),
+  }
+  Ok(())
+}
+
+/// Prints the results of a challenge ordered by score.
+fn compare(args: &Args, sealed: &SealedChallenge, results: &[String])
+  -> Result<(), Box<dyn Error>>
+{
+  let challenge = sealed.open()?;
+  let words = args.words(challenge.unseen_set_id())?;
+  println!(
+    "challenge {}: seed {}, ratio {}, {:?}, {} lives, {:?}",
+    sealed.id(),
+    challenge.seed(),
+    challenge.seen_threshold().ratio(),
+    challenge.unseen_set_id(),
+    challenge.lives(),
+    challenge.mode()
+  );
+
+  let mut scores = Vec::new();
+  for (i, result) in results.iter().enumerate() {
+    let game_over = result
+      .parse::<SealedEncodedGameOver>()
+      .and_then(|x| sealed.result(x, words.clone()));
+    match game_over {
+      Ok(game_over) => scores.push((i, game_over.score())),
+      Err(e) => println!("result {} rejected: {}", i + 1, e),
+    }
+  }
+
+  scores.sort_by(|x, y| y.1.cmp(&x.1));
+  for (rank, (i, score)) in scores.iter().enumerate() {
+    println!("{:>4}. result {:<4} {:>6}", rank + 1, i + 1, score);
+  }
+  Ok(())
+}
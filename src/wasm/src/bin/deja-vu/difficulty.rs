@@ -0,0 +1,136 @@
+use std::{
+  collections::HashMap,
+  error::Error,
+  fs,
+  io::{
+    self,
+    Read,
+  },
+};
+
+use deja_vu_wasm::{
+  analytics::WordDifficulty,
+  coder::{
+    SealedEncodedGameOver,
+    UnseenSetID,
+  },
+  game_over::GameOver,
+  history::Record,
+};
+use serde_json::{
+  json,
+  Value,
+};
+
+use crate::args::Args;
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  // Every positional argument is a file of share codes. Reads from stdin if there are none.
+  let mut corpus = String::new();
+  if args.positional().is_empty() {
+    io::stdin().read_to_string(&mut corpus)?;
+  }
+  for path in args.positional() {
+    corpus += &fs::read_to_string(path)?;
+    corpus.push('\n');
+  }
+
+  let mut words: HashMap<UnseenSetID, Vec<String>> = HashMap::new();
+  let mut difficulty: HashMap<UnseenSetID, WordDifficulty<String>> = HashMap::new();
+  let mut order: Vec<UnseenSetID> = Vec::new();
+  let mut games = 0;
+  let mut skipped = 0;
+
+  for (i, line) in corpus.lines().map(str::trim).enumerate() {
+    if line.is_empty() {
+      continue;
+    }
+    let game_over = parse(line).and_then(|sealed| {
+      let id = sealed.unseen_set_id().clone();
+      if !words.contains_key(&id) {
+        words.insert(id.clone(), args.words(&id)?);
+      }
+      GameOver::try_from((sealed, words[&id].clone()))
+    });
+    match game_over {
+      Ok(game_over) => {
+        let id = game_over.unseen_set_id().clone();
+        if !order.contains(&id) {
+          order.push(id.clone());
+        }
+        difficulty.entry(id).or_default().add(&game_over);
+        games += 1;
+      }
+      Err(e) => {
+        eprintln!("line {}: {}", i + 1, e.to_string().trim_end());
+        skipped += 1;
+      }
+    }
+  }
+
+  let min_commits = args.parse_or("min-commits", 3)?;
+  let limit = args.parse_or("limit", 50)?;
+
+  if args.flag("json") {
+    let report: Value = order
+      .iter()
+      .map(|id| {
+        let words: Value = difficulty[id]
+          .ranked(min_commits)
+          .into_iter()
+          .take(limit)
+          .map(|(word, x)| {
+            json!({
+              "word": word,
+              "commits": x.commits(),
+              "misses": x.misses(),
+              "miss_rate": x.miss_rate(),
+              "false_alarms": x.false_alarms(),
+              "false_alarm_rate": x.false_alarm_rate(),
+              "error_rate": x.error_rate(),
+            })
+          })
+          .collect();
+        json!({ "unseen_set_id": id, "words": words })
+      })
+      .collect();
+    println!("{}", serde_json::to_string_pretty(&report)?);
+  } else {
+    println!("{} games, {} skipped", games, skipped);
+    for id in &order {
+      println!();
+      println!("{:?}", id);
+      println!(
+        "{:<24} {:>7} {:>6} {:>6} {:>6} {:>6} {:>6}",
+        "word", "commits", "miss", "rate", "fa", "rate", "error"
+      );
+      for (word, x) in difficulty[id].ranked(min_commits).into_iter().take(limit) {
+        let rate = |x: Option<f64>| x.map_or("-".into(), |x| format!("{:.2}", x));
+        println!(
+          "{:<24} {:>7} {:>6} {:>6} {:>6} {:>6} {:>6.2}",
+          word,
+          x.commits(),
+          x.misses(),
+          rate(x.miss_rate()),
+          x.false_alarms(),
+          rate(x.false_alarm_rate()),
+          x.error_rate()
+        );
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Parses a line that is either a share URL, a query string or a JSON record as exported from a
+/// history.
+fn parse(line: &str) -> Result<SealedEncodedGameOver, Box<dyn Error>>
+{
+  if line.starts_with('{') {
+    Ok(serde_json::from_str::<Record>(line)?.game().clone())
+  } else {
+    line.parse()
+  }
+}
@@ -0,0 +1,99 @@
+use std::error::Error;
+
+use deja_vu_wasm::{
+  coder::{
+    Authenticity,
+    CoderError,
+    CoderRegistry,
+    EncodedGameOver,
+    SealedEncodedGameOver,
+  },
+  game_over::GameOver,
+  plausibility::Plausibility,
+};
+use serde_json::json;
+
+use crate::args::Args;
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  let input = match args.positional() {
+    [input] => input,
+    _ => return Err("expected exactly one share URL or query string".into()),
+  };
+
+  let sealed: SealedEncodedGameOver = input.parse()?;
+
+  // Verifies the version and the checksum, and the MAC if there is a key.
+  let authenticity = match args.key()? {
+    Some(key) => EncodedGameOver::try_from((sealed.clone(), &key))?.authenticity(),
+    None => EncodedGameOver::try_from(sealed.clone())?.authenticity(),
+  };
+  let authenticity = match authenticity {
+    Authenticity::Verified => "verified",
+    Authenticity::Unverified => "unverified",
+  };
+
+  let words = args.words(sealed.unseen_set_id())?;
+  let registry = CoderRegistry::<String>::default();
+  let coder = registry.coder(sealed.version())?;
+  let present = |has_checksum: bool| if has_checksum { "matched" } else { "absent" };
+  let (game_over, element_checksum, commit_checksum) =
+    match GameOver::try_from((sealed.clone(), words)) {
+      Ok(game_over) => (
+        Some(game_over),
+        present(coder.has_element_checksum()),
+        present(coder.has_commit_checksum()),
+      ),
+      Err(e) => match e.downcast_ref() {
+        Some(CoderError::BadElementChecksum) => (None, "mismatched", "unknown"),
+        Some(CoderError::BadCommitChecksum) => (None, "matched", "mismatched"),
+        None => return Err(e),
+      },
+    };
+
+  if args.flag("json") {
+    let mut report = json!({
+      "version": sealed.version(),
+      "checksum": sealed.checksum(),
+      "unseen_set_id": sealed.unseen_set_id(),
+      "element_checksum": element_checksum,
+      "commit_checksum": commit_checksum,
+      "authenticity": authenticity,
+    });
+    if let Some(game_over) = &game_over {
+      report["seed"] = json!(game_over.seed());
+      report["mode"] = json!(game_over.mode());
+      report["score"] = json!(game_over.score());
+      report["plausibility"] = json!(Plausibility::default().check(game_over));
+      report["commits"] = serde_json::from_str(&game_over.to_json()?)?;
+    }
+    println!("{}", serde_json::to_string_pretty(&report)?);
+  } else {
+    println!("version:          {}", sealed.version());
+    println!("checksum:         {} (ok)", sealed.checksum());
+    println!("unseen set:       {:?}", sealed.unseen_set_id());
+    println!("element checksum: {}", element_checksum);
+    println!("commit checksum:  {}", commit_checksum);
+    println!("authenticity:     {}", authenticity);
+    if let Some(game_over) = &game_over {
+      println!("seed:             {}", game_over.seed());
+      println!("mode:             {:?}", game_over.mode());
+      println!("score:            {}", game_over.score());
+      println!(
+        "plausibility:     {}",
+        Plausibility::default().check(game_over)
+      );
+      println!();
+      print!("{}", game_over.to_text_table());
+    }
+  }
+
+  match game_over {
+    Some(_) => Ok(()),
+    None if commit_checksum == "mismatched" => {
+      Err("the commits are not consistent with the commit checksum of the game".into())
+    }
+    None => Err("the word list does not match the element checksum of the game".into()),
+  }
+}
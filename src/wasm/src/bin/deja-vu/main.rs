@@ -0,0 +1,160 @@
+mod archive;
+mod args;
+mod challenge;
+mod difficulty;
+mod inspect;
+mod migrate;
+mod play;
+mod race;
+mod replay;
+mod search;
+mod serve;
+mod simulate;
+
+use std::{
+  env,
+  error::Error,
+  process,
+};
+
+use args::Args;
+
+const USAGE: &str = "\
+usage: deja-vu <command> [options]
+
+commands:
+  play    Play a game in the terminal.
+          --set <id>          Unseen set, default Top999WiktionaryFr.
+          --words <path>      Word list to use instead of the one of the unseen set.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+          --seed <n>          Seed of the game, default the current time.
+          --ratio <r>         Seen ratio in range [0.0, 1.0], default 0.4.
+          --mode <mode>       classic, adaptive, window:<k>, n-back:<n> or forced-choice.
+          --lives <n>         Lives the game starts with, default 3.
+          --challenge <url>   Play a challenge instead, its result references the challenge.
+          --url <url>         Base of the share URL.
+          --layout <layout>   Where the share URL holds the code: query, fragment or path.
+          --history <path>    JSON-lines file the game is added to when it is over.
+          --key-file <path>   File with a hex key the share code is signed with.
+
+  challenge [<challenge> <results...>]
+          Without arguments, print a challenge link carrying the settings of a game but no result.
+          Given a challenge and share URLs of its results, rank the results that reference it.
+          --set, --seed, --ratio, --mode and --lives as for play.
+          --url <url>         Base of the challenge URL.
+          --words <path>      Word list to use instead of the one of the unseen set.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+
+  race <url>
+          Play the same game as a share URL and compare each commit with it.
+          --words <path>      Word list to use instead of the one of the unseen set.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+
+  inspect <url>
+          Verify and replay a share URL or query string.
+          --words <path>      Word list to use instead of the one of the unseen set.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+          --key-file <path>   File with a hex key to verify the signature of the code with.
+          --json              Print the replay as JSON.
+
+  archive [files...]
+          Pack share codes, one per line or as exported history records, into a single archive
+          written to stdout. Reads from stdin if no file is given.
+          --title <title>     Title of the archive, e.g. the name of the tournament.
+          --extract <path>    Verify every game of an archive instead and print their share queries.
+
+  migrate [files...]
+          Re-encode share codes of any version with the latest coder, one per line, and check that
+          each new code replays exactly like the old one. Reads from stdin if no file is given.
+          --words <path>      Word list to use instead of the one of the unseen set.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+          --key-file <path>   File with a hex key the new codes are signed with.
+
+  serve   Serve a leaderboard over HTTP.
+          --address <addr>    Address to listen on, default 0.0.0.0:8080.
+          --store <path>      File the entries are stored in, default leaderboard.jsonl.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+          --key-file <path>   File with a hex key. Only games signed with it are accepted.
+          --plausibility      Reject games with patterns that are unlikely for a human player, or
+                              with too few reaction times to check.
+          --challenges <path> File with a challenge URL per line. Only results of these may start
+                              with other than the default amount of lives.
+
+          POST /submit?name=<name>                      Submit a share URL or query string.
+          GET  /rankings?set=<id>&day=<YYYY-MM-DD>&limit=<n>&mode=<mode>&lives=<n>
+                                                        Mode and lives default to a classic game.
+
+  simulate
+          Play many games with synthetic players and summarize the scores.
+          --set <ids>         Comma separated unseen sets, default Top999WiktionaryFr.
+          --ratio <rs>        Comma separated seen ratios, default 0.4.
+          --player <ps>       Comma separated players: perfect, capacity:<k>, forgetting:<half-life>
+                              or biased:<p>, where players with memory take a bias as '@<p>'.
+          --mode <mode>       classic, adaptive, window:<k>, n-back:<n> or forced-choice.
+          --games <n>         Games per ratio and player, default 200.
+          --seed <n>          Seed of the seeds of the games, default 0.
+          --max-commits <n>   Stop games after this many commits, default 10000.
+          --words <path>      Word list to use instead of the one of the unseen set.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+          --json              Print the summaries as JSON.
+
+  search  Scan seeds in parallel for games whose first trials meet every criterion, ordered by
+          lure density. A lure is an unseen element one edit away from an element shown before.
+          --from <n> --to <n> Range of seeds to scan, default 0 to 100000.
+          --random <seed>     Scan random seeds generated from <seed> instead of a range.
+          --count <n>         Amount of random seeds, default 100000.
+          --criteria <cs>     Comma separated criteria: no-seen-within:<n>, max-length:<n>,
+                              min-lure-density:<r> or min-seen-density:<r>.
+          --trials <n>        Amount of trials the criteria applies to, default 20.
+          --limit <n>         Amount of seeds to print, default 10.
+          --threads <n>       Amount of threads, default every core.
+          --set <id>          Unseen set, default Top999WiktionaryFr.
+          --ratio <r>         Seen ratio in range [0.0, 1.0], default 0.4.
+          --mode <mode>       classic, adaptive, window:<k>, n-back:<n> or forced-choice.
+          --words <path>      Word list to use instead of the one of the unseen set.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+          --json              Print the seeds as JSON.
+
+  difficulty [files...]
+          Replay a corpus of share codes and rank the words of each unseen set by how often they
+          are misjudged. Each line is a share URL, a query string or an exported history record.
+          Reads from stdin if no file is given.
+          --min-commits <n>   Leave out words with fewer commits, default 3.
+          --limit <n>         Amount of words per unseen set, default 50.
+          --words <path>      Word list to use instead of the one of the unseen set.
+          --dictionary <dir>  Directory of the word lists, default public/dictionary.
+          --json              Print the report as JSON.
+";
+
+/// Flags that never take a value.
+const FLAGS: &[&str] = &["json", "plausibility"];
+
+fn main()
+{
+  let mut args = env::args().skip(1);
+  let command = args.next().unwrap_or_default();
+
+  let args = Args::parse(args, FLAGS);
+
+  let result: Result<(), Box<dyn Error>> = match command.as_str() {
+    "play" => play::run(&args),
+    "challenge" => challenge::run(&args),
+    "race" => race::run(&args),
+    "inspect" => inspect::run(&args),
+    "archive" => archive::run(&args),
+    "migrate" => migrate::run(&args),
+    "serve" => serve::run(&args),
+    "simulate" => simulate::run(&args),
+    "search" => search::run(&args),
+    "difficulty" => difficulty::run(&args),
+    _ => {
+      eprint!("{}", USAGE);
+      process::exit(2);
+    }
+  };
+
+  if let Err(e) = result {
+    eprintln!("error: {}", e);
+    process::exit(1);
+  }
+}
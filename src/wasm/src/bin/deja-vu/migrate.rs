@@ -0,0 +1,77 @@
+use std::{
+  collections::HashMap,
+  error::Error,
+  fs,
+  io::{
+    self,
+    Read,
+  },
+};
+
+use deja_vu_wasm::coder::{
+  SealedEncodedGameOver,
+  UnseenSetID,
+};
+use url::Url;
+
+use crate::args::Args;
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  // Every positional argument is a file of share codes. Reads from stdin if there are none.
+  let mut corpus = String::new();
+  if args.positional().is_empty() {
+    io::stdin().read_to_string(&mut corpus)?;
+  }
+  for path in args.positional() {
+    corpus += &fs::read_to_string(path)?;
+    corpus.push('\n');
+  }
+
+  let key = args.key()?;
+  let mut words: HashMap<UnseenSetID, Vec<String>> = HashMap::new();
+  let mut skipped = 0;
+
+  for (i, line) in corpus.lines().map(str::trim).enumerate() {
+    if line.is_empty() {
+      continue;
+    }
+    let migrated = line.parse().and_then(|sealed: SealedEncodedGameOver| {
+      let id = sealed.unseen_set_id().clone();
+      if !words.contains_key(&id) {
+        words.insert(id.clone(), args.words(&id)?);
+      }
+      let mut migrated = sealed.migrate(words[&id].clone())?;
+      if let Some(key) = &key {
+        migrated = migrated.with_mac(key);
+      }
+      Ok(migrated)
+    });
+    match migrated {
+      Ok(migrated) => println!("{}", relink(line, &migrated)?),
+      Err(e) => {
+        eprintln!("line {}: {}", i + 1, e.to_string().trim_end());
+        skipped += 1;
+      }
+    }
+  }
+
+  if skipped > 0 {
+    eprintln!("{} line(s) skipped", skipped);
+  }
+  Ok(())
+}
+
+/// `line` with its share code replaced by `sealed`. A URL keeps everything but its query.
+fn relink(line: &str, sealed: &SealedEncodedGameOver) -> Result<String, Box<dyn Error>>
+{
+  let query = serde_urlencoded::to_string(sealed)?;
+  Ok(match Url::parse(line) {
+    Ok(mut url) => {
+      url.set_query(Some(&query));
+      url.to_string()
+    }
+    Err(_) if line.starts_with('?') => format!("?{}", query),
+    Err(_) => query,
+  })
+}
@@ -0,0 +1,167 @@
+use std::{
+  error::Error,
+  io::{
+    self,
+    BufRead,
+    Write,
+  },
+  time::{
+    Instant,
+    SystemTime,
+    UNIX_EPOCH,
+  },
+};
+
+use deja_vu_wasm::{
+  coder::{
+    LatestCoder,
+    SealedChallenge,
+    SealedEncodedGameOver,
+    ShareLayout,
+  },
+  game::{
+    Confidence,
+    Game,
+  },
+  game_over::GameOver,
+  history::{
+    FileStore,
+    HistoryStore,
+    Record,
+  },
+};
+
+use crate::{
+  args::Args,
+  challenge,
+};
+
+const DEFAULT_URL: &str = "https://felixnaredi.github.io/deja-vu/game-over/";
+
+/// What the player answered.
+pub enum Answer
+{
+  Seen(Option<Confidence>),
+  Unseen(Option<Confidence>),
+  Choice(usize),
+  Quit,
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  let sealed_challenge: Option<SealedChallenge> =
+    args.get("challenge").map(str::parse).transpose()?;
+  let challenge = match &sealed_challenge {
+    Some(sealed) => sealed.open()?,
+    None => challenge::from_args(args)?,
+  };
+  let words = args.words(challenge.unseen_set_id())?;
+
+  let mut game = challenge.game(words);
+
+  println!("Answer with 's' for seen and 'u' for unseen, optionally followed by a confidence");
+  println!("1 (sure), 2 (think) or 3 (guess). Pairs are answered with '1' or '2'. Quit with 'q'.");
+
+  let stdin = io::stdin();
+  let mut lines = stdin.lock().lines();
+
+  while !game.finished() {
+    println!();
+    println!(
+      "{}  score: {}",
+      hearts(game.lives(), game.initial_lives()),
+      game.score()
+    );
+
+    let pair = game.expects_pair();
+    if pair {
+      let (x, y) = game.next_pair()?;
+      println!("  1) {}    2) {}", x, y);
+    } else {
+      println!("  {}", game.next()?);
+    }
+    let shown = Instant::now();
+
+    let correct = loop {
+      print!("> ");
+      io::stdout().flush()?;
+      let line = match lines.next() {
+        Some(line) => line?,
+        None => return Ok(()),
+      };
+      game.set_reaction_time(shown.elapsed().as_millis().min(u32::MAX as u128) as u32);
+      match (parse_answer(&line), pair) {
+        (Some(Answer::Quit), _) => return Ok(()),
+        (Some(Answer::Seen(c)), false) => break commit(&mut game, true, c)?,
+        (Some(Answer::Unseen(c)), false) => break commit(&mut game, false, c)?,
+        (Some(Answer::Choice(i)), true) => break game.commit_choice(i)?,
+        _ => println!("invalid answer"),
+      }
+    };
+    println!("{}", if correct { "correct" } else { "wrong" });
+  }
+
+  let game_over = GameOver::from(game);
+  println!();
+  println!("Game over! Score: {}", game_over.score());
+  println!();
+  print!("{}", game_over.to_text_table());
+  let mut sealed = SealedEncodedGameOver::new::<LatestCoder, _>(&game_over)?;
+  if let Some(challenge) = &sealed_challenge {
+    sealed = challenge.sign(sealed);
+  }
+  if let Some(key) = args.key()? {
+    sealed = sealed.with_mac(&key);
+  }
+  println!();
+  println!(
+    "{}",
+    sealed.share_url(
+      args.get("url").unwrap_or(DEFAULT_URL),
+      args.parse_or("layout", ShareLayout::Query)?
+    )?
+  );
+
+  if let Some(path) = args.get("history") {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+    FileStore::new(path).push(Record::new(timestamp, sealed))?;
+  }
+
+  Ok(())
+}
+
+pub fn parse_answer(line: &str) -> Option<Answer>
+{
+  let line = line.trim();
+  let confidence = match line.get(1..) {
+    Some("") | None => None,
+    Some(x) => Some(Confidence::try_from(x.parse::<u8>().ok()?).ok()?),
+  };
+  match line.get(..1)? {
+    "s" => Some(Answer::Seen(confidence)),
+    "u" => Some(Answer::Unseen(confidence)),
+    "1" if confidence.is_none() => Some(Answer::Choice(0)),
+    "2" if confidence.is_none() => Some(Answer::Choice(1)),
+    "q" => Some(Answer::Quit),
+    _ => None,
+  }
+}
+
+fn commit(
+  game: &mut Game<String>,
+  seen: bool,
+  confidence: Option<Confidence>,
+) -> Result<bool, Box<dyn Error>>
+{
+  Ok(match (seen, confidence) {
+    (true, Some(c)) => game.commit_seen_with_confidence(c)?,
+    (true, None) => game.commit_seen()?,
+    (false, Some(c)) => game.commit_unseen_with_confidence(c)?,
+    (false, None) => game.commit_unseen()?,
+  })
+}
+
+pub fn hearts(lives: usize, initial_lives: usize) -> String
+{
+  "♥".repeat(lives) + &"♡".repeat(initial_lives - lives)
+}
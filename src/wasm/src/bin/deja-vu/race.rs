@@ -0,0 +1,99 @@
+use std::{
+  error::Error,
+  io::{
+    self,
+    BufRead,
+    Write,
+  },
+};
+
+use deja_vu_wasm::{
+  coder::SealedEncodedGameOver,
+  game_over::{
+    GameOver,
+    GhostRace,
+  },
+};
+
+use crate::{
+  args::Args,
+  play::{
+    hearts,
+    parse_answer,
+    Answer,
+  },
+  replay,
+};
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  let sealed: SealedEncodedGameOver = match args.positional() {
+    [input] => input.parse()?,
+    _ => return Err("expected exactly one share URL or query string".into()),
+  };
+  let words = args.words(sealed.unseen_set_id())?;
+  let ghost = GameOver::try_from((sealed, words.clone()))?;
+  let mut race = GhostRace::new(&ghost, words);
+
+  println!(
+    "Racing a ghost with score {}. Answer with 's' for seen and 'u' for unseen, pairs with '1'",
+    ghost.score()
+  );
+  println!("or '2'. Quit with 'q'.");
+
+  let stdin = io::stdin();
+  let mut lines = stdin.lock().lines();
+
+  while !race.game().finished() {
+    println!();
+    println!(
+      "{}  score: {}",
+      hearts(race.game().lives(), race.game().initial_lives()),
+      race.game().score()
+    );
+
+    let pair = race.game().expects_pair();
+    if pair {
+      let (x, y) = race.next_pair()?;
+      println!("  1) {}    2) {}", x, y);
+    } else {
+      println!("  {}", race.next_element()?);
+    }
+
+    let step = loop {
+      print!("> ");
+      io::stdout().flush()?;
+      let line = match lines.next() {
+        Some(line) => line?,
+        None => return Ok(()),
+      };
+      match (parse_answer(&line), pair) {
+        (Some(Answer::Quit), _) => return Ok(()),
+        (Some(Answer::Seen(_)), false) => break race.commit_seen()?,
+        (Some(Answer::Unseen(_)), false) => break race.commit_unseen()?,
+        (Some(Answer::Choice(i)), true) => break race.commit_choice(i)?,
+        _ => println!("invalid answer"),
+      }
+    };
+    let outcome = |correct| if correct { "correct" } else { "wrong" };
+    match step.ghost_correct {
+      Some(ghost) => println!("{} (ghost: {})", outcome(step.correct), outcome(ghost)),
+      None => println!("{} (ghost: out of lives)", outcome(step.correct)),
+    }
+  }
+
+  println!();
+  println!(
+    "Game over! Score: {}, ghost: {}",
+    race.game().score(),
+    ghost.score()
+  );
+  println!();
+  print!("{}", replay::diff_table(&race.diff()));
+  if let Some(i) = race.lives_diverged_at() {
+    println!();
+    println!("Lives diverged at commit {}.", i + 1);
+  }
+
+  Ok(())
+}
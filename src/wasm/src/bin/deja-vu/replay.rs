@@ -0,0 +1,55 @@
+use std::fmt::Write;
+
+use deja_vu_wasm::game_over::{
+  Commit,
+  StepDiff,
+};
+
+/// The element of a commit, or both elements if it was a pair.
+fn element(commit: &Commit<String>) -> String
+{
+  match commit.alternative() {
+    Some(y) => format!("{} / {}", commit.element(), y),
+    None => commit.element().clone(),
+  }
+}
+
+fn mark(correct: bool) -> &'static str
+{
+  if correct {
+    "✓"
+  } else {
+    "✗"
+  }
+}
+
+/// The commits of a `GhostRace` side by side. Rows where the games diverge are marked with '*'.
+pub fn diff_table(diff: &[StepDiff<String>]) -> String
+{
+  let mut s = format!(
+    "{:>4}  {:<24} {:<2} {:>5}   {:<24} {:<2} {:>5}\n",
+    "#", "you", "", "lives", "ghost", "", "lives"
+  );
+  for x in diff {
+    let side = |commit: &Option<Commit<String>>| match commit {
+      Some(commit) => (element(commit), mark(commit.correct())),
+      None => (String::new(), ""),
+    };
+    let (live, live_mark) = side(&x.live);
+    let (ghost, ghost_mark) = side(&x.ghost);
+    writeln!(
+      s,
+      "{:>4}  {:<24} {:<2} {:>5}   {:<24} {:<2} {:>5} {}",
+      x.index + 1,
+      live,
+      live_mark,
+      x.lives,
+      ghost,
+      ghost_mark,
+      x.ghost_lives,
+      if x.diverges() { "*" } else { "" }
+    )
+    .unwrap();
+  }
+  s
+}
@@ -0,0 +1,77 @@
+use std::error::Error;
+
+use deja_vu_wasm::{
+  coder::UnseenSetID,
+  search::{
+    Criterion,
+    SearchBuilder,
+    Seeds,
+  },
+};
+use serde_json::{
+  json,
+  Value,
+};
+
+use crate::args::{
+  parse_mode,
+  Args,
+};
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  let unseen_set_id: UnseenSetID = args.parse_or("set", UnseenSetID::Top999WiktionaryFr)?;
+  let words = args.words(&unseen_set_id)?;
+  let ratio: f64 = args.parse_or("ratio", 0.4)?;
+
+  let seeds = match args.get("random") {
+    Some(seed) => Seeds::Random {
+      seed: seed.parse()?,
+      count: args.parse_or("count", 100000)?,
+    },
+    None => Seeds::Range(args.parse_or("from", 0)?..args.parse_or("to", 100000)?),
+  };
+  let criteria = match args.get("criteria") {
+    Some(s) => s
+      .split(',')
+      .map(|x| x.trim().parse())
+      .collect::<Result<Vec<Criterion>, _>>()?,
+    None => Vec::new(),
+  };
+
+  let candidates = SearchBuilder::default()
+    .unseen_set_id(unseen_set_id)
+    .seen_threshold(ratio.try_into()?)
+    .mode(parse_mode(args.get("mode").unwrap_or("classic"))?)
+    .seeds(seeds)
+    .criteria(criteria)
+    .trials(args.parse_or("trials", 20)?)
+    .limit(args.parse_or("limit", 10)?)
+    .threads(args.parse_or("threads", 0)?)
+    .build()?
+    .run(&words.iter().map(|x| x.as_str()).collect::<Vec<_>>())?;
+
+  if args.flag("json") {
+    let candidates: Value = candidates
+      .iter()
+      .map(|x| {
+        json!({
+          "seed": x.seed,
+          "lure_density": x.lure_density,
+          "seen_density": x.seen_density,
+        })
+      })
+      .collect();
+    println!("{}", serde_json::to_string_pretty(&candidates)?);
+  } else {
+    println!("{:>20} {:>6} {:>6}", "seed", "lure", "seen");
+    for x in &candidates {
+      println!(
+        "{:>20} {:>6.2} {:>6.2}",
+        x.seed, x.lure_density, x.seen_density
+      );
+    }
+  }
+
+  Ok(())
+}
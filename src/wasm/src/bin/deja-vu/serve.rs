@@ -0,0 +1,325 @@
+use std::{
+  collections::HashMap,
+  error::Error,
+  fmt::Display,
+  fs::{
+    self,
+    File,
+    OpenOptions,
+  },
+  io::{
+    BufRead,
+    BufReader,
+    Read,
+    Write,
+  },
+  net::{
+    TcpListener,
+    TcpStream,
+  },
+  path::Path,
+  time::{
+    Duration,
+    SystemTime,
+    UNIX_EPOCH,
+  },
+};
+
+use deja_vu_wasm::{
+  coder::{
+    SealedChallenge,
+    SealedEncodedGameOver,
+    UnseenSetID,
+  },
+  game::{
+    GameMode,
+    INITIAL_LIVES_AMOUNT,
+  },
+  leaderboard::{
+    Day,
+    Entry,
+    Leaderboard,
+    LeaderboardError,
+  },
+  plausibility::Plausibility,
+  words,
+};
+use serde_json::{
+  json,
+  Value,
+};
+
+use crate::args::{
+  parse_mode,
+  Args,
+};
+
+const DEFAULT_ADDRESS: &str = "0.0.0.0:8080";
+const DEFAULT_STORE: &str = "leaderboard.jsonl";
+const DEFAULT_DICTIONARY: &str = "public/dictionary";
+
+/// Largest accepted request body in bytes.
+const MAX_BODY_LENGTH: usize = 1 << 16;
+
+/// Largest accepted request line or header line in bytes.
+const MAX_LINE_LENGTH: usize = 1 << 13;
+
+/// Largest accepted amount of headers in a request.
+const MAX_HEADER_COUNT: usize = 64;
+
+/// Time a connection may stay idle while reading the request or writing the response. Requests
+/// are handled one at a time, so a client that sends nothing would otherwise block the server.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A response with a status code and a JSON body.
+struct Response(u16, Value);
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  let dictionary = Path::new(args.get("dictionary").unwrap_or(DEFAULT_DICTIONARY));
+  let mut words = HashMap::new();
+  for id in UnseenSetID::all() {
+    if let Ok(x) = words::load_unseen_set(dictionary, &id) {
+      words.insert(id, x);
+    }
+  }
+  if words.is_empty() {
+    return Err(format!("no word lists found in '{}'", dictionary.display()).into());
+  }
+
+  // The store is a file with one `Entry` as JSON per line, replayed on start.
+  let store = Path::new(args.get("store").unwrap_or(DEFAULT_STORE));
+  let mut leaderboard = Leaderboard::new(words);
+  if let Some(key) = args.key()? {
+    leaderboard = leaderboard.with_key(key);
+  }
+  if args.flag("plausibility") {
+    leaderboard = leaderboard.with_plausibility(Plausibility::default());
+  }
+  if let Some(path) = args.get("challenges") {
+    for line in fs::read_to_string(path)?.lines().map(str::trim) {
+      if !line.is_empty() {
+        leaderboard = leaderboard.with_challenge(line.parse::<SealedChallenge>()?);
+      }
+    }
+  }
+  if store.exists() {
+    // Entries that no longer verify, e.g. after the rules of the leaderboard changed, are skipped.
+    for (i, line) in fs::read_to_string(store)?.lines().enumerate() {
+      if line.is_empty() {
+        continue;
+      }
+      if let Err(e) = leaderboard.restore(serde_json::from_str::<Entry>(line)?) {
+        eprintln!("line {}: {}", i + 1, e.to_string().trim_end());
+      }
+    }
+  }
+  let mut store = OpenOptions::new().create(true).append(true).open(store)?;
+
+  let address = args.get("address").unwrap_or(DEFAULT_ADDRESS);
+  let listener = TcpListener::bind(address)?;
+  eprintln!(
+    "serving {} entries on http://{}",
+    leaderboard.entries().len(),
+    address
+  );
+
+  for stream in listener.incoming() {
+    if let Err(e) = stream
+      .map_err(|e| e.into())
+      .and_then(|mut stream| handle(&mut stream, &mut leaderboard, &mut store))
+    {
+      eprintln!("error: {}", e);
+    }
+  }
+  Ok(())
+}
+
+fn handle(
+  stream: &mut TcpStream,
+  leaderboard: &mut Leaderboard,
+  store: &mut File,
+) -> Result<(), Box<dyn Error>>
+{
+  stream.set_read_timeout(Some(TIMEOUT))?;
+  stream.set_write_timeout(Some(TIMEOUT))?;
+  let mut reader = BufReader::new(stream.try_clone()?);
+
+  let line = read_line(&mut reader)?;
+  let mut parts = line.split_whitespace();
+  let method = parts.next().unwrap_or_default().to_string();
+  let target = parts.next().unwrap_or_default().to_string();
+
+  let mut length = 0;
+  for i in 0.. {
+    if i > MAX_HEADER_COUNT {
+      return Err("request has too many headers".into());
+    }
+    let header = read_line(&mut reader)?;
+    let header = header.trim();
+    if header.is_empty() {
+      break;
+    }
+    if let Some((name, value)) = header.split_once(':') {
+      if name.eq_ignore_ascii_case("content-length") {
+        length = value.trim().parse()?;
+      }
+    }
+  }
+
+  let response = if length > MAX_BODY_LENGTH {
+    Response(413, json!({ "error": "request body is too large" }))
+  } else {
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    route(
+      &method,
+      &target,
+      &String::from_utf8_lossy(&body),
+      leaderboard,
+      store,
+    )
+  };
+
+  let body = response.1.to_string();
+  write!(
+    stream,
+    "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+     Access-Control-Allow-Origin: *\r\nConnection: close\r\n\r\n{}",
+    response.0,
+    reason(response.0),
+    body.len(),
+    body
+  )?;
+  Ok(())
+}
+
+/// Reads a line of at most `MAX_LINE_LENGTH` bytes.
+fn read_line(reader: &mut impl BufRead) -> Result<String, Box<dyn Error>>
+{
+  let mut line = String::new();
+  reader
+    .take(MAX_LINE_LENGTH as u64 + 1)
+    .read_line(&mut line)?;
+  if line.len() > MAX_LINE_LENGTH {
+    return Err("request line is too long".into());
+  }
+  Ok(line)
+}
+
+/// Routes a request.
+///
+/// - `POST /submit?name=<name>` with a share URL or query string as body.
+/// - `GET /rankings?set=<id>&day=<YYYY-MM-DD>&limit=<n>` where every parameter is optional.
+fn route(
+  method: &str,
+  target: &str,
+  body: &str,
+  leaderboard: &mut Leaderboard,
+  store: &mut File,
+) -> Response
+{
+  let (path, query) = target.split_once('?').unwrap_or((target, ""));
+  let query: HashMap<String, String> = match serde_urlencoded::from_str(query) {
+    Ok(query) => query,
+    Err(e) => return bad_request(e),
+  };
+
+  match (method, path) {
+    ("POST", "/submit") => {
+      let sealed: SealedEncodedGameOver = match body.parse() {
+        Ok(sealed) => sealed,
+        Err(e) => return bad_request(e),
+      };
+      let name = query.get("name").cloned().unwrap_or_default();
+      let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs());
+
+      match leaderboard.submit(name, sealed, timestamp) {
+        Ok(entry) => match serde_json::to_string(entry)
+          .map_err(|e| e.into())
+          .and_then(|line| writeln!(store, "{}", line).map_err(Box::<dyn Error>::from))
+        {
+          Ok(()) => Response(201, entry_json(None, entry)),
+          Err(e) => Response(500, json!({ "error": e.to_string() })),
+        },
+        Err(e @ LeaderboardError::Duplicate) => Response(409, json!({ "error": e.to_string() })),
+        Err(e) => bad_request(e),
+      }
+    }
+
+    ("GET", "/rankings") => {
+      let set = match query
+        .get("set")
+        .map(|x| x.parse::<UnseenSetID>())
+        .transpose()
+      {
+        Ok(set) => set,
+        Err(e) => return bad_request(e),
+      };
+      let day = match query.get("day").map(|x| x.parse::<Day>()).transpose() {
+        Ok(day) => day,
+        Err(e) => return bad_request(e),
+      };
+      let limit = match query.get("limit").map(|x| x.parse::<usize>()).transpose() {
+        Ok(limit) => limit.unwrap_or(usize::MAX),
+        Err(e) => return bad_request(e),
+      };
+      let mode = match query.get("mode").map(|x| parse_mode(x)).transpose() {
+        Ok(mode) => mode.unwrap_or(GameMode::Classic),
+        Err(e) => return bad_request(e),
+      };
+      let lives = match query.get("lives").map(|x| x.parse::<usize>()).transpose() {
+        Ok(lives) => lives.unwrap_or(INITIAL_LIVES_AMOUNT),
+        Err(e) => return bad_request(e),
+      };
+
+      Response(
+        200,
+        leaderboard
+          .ranking(&mode, lives, set.as_ref(), day)
+          .into_iter()
+          .take(limit)
+          .enumerate()
+          .map(|(i, entry)| entry_json(Some(i + 1), entry))
+          .collect(),
+      )
+    }
+
+    _ => Response(404, json!({ "error": "not found" })),
+  }
+}
+
+fn bad_request<E: Display>(e: E) -> Response
+{
+  Response(400, json!({ "error": e.to_string().trim_end() }))
+}
+
+fn entry_json(rank: Option<usize>, entry: &Entry) -> Value
+{
+  json!({
+    "rank": rank,
+    "name": entry.name(),
+    "score": entry.score(),
+    "mode": entry.mode(),
+    "lives": entry.lives(),
+    "unseen_set_id": entry.unseen_set_id(),
+    "day": entry.day().to_string(),
+    "timestamp": entry.timestamp(),
+    "share": serde_urlencoded::to_string(entry.sealed()).unwrap_or_default(),
+  })
+}
+
+fn reason(status: u16) -> &'static str
+{
+  match status {
+    200 => "OK",
+    201 => "Created",
+    400 => "Bad Request",
+    404 => "Not Found",
+    409 => "Conflict",
+    413 => "Payload Too Large",
+    _ => "Internal Server Error",
+  }
+}
@@ -0,0 +1,138 @@
+use std::error::Error;
+
+use deja_vu_wasm::{
+  coder::UnseenSetID,
+  game::SeenThreshold,
+  simulation::{
+    Player,
+    SimulationBuilder,
+    Summary,
+  },
+};
+use serde_json::{
+  json,
+  Value,
+};
+
+use crate::args::{
+  parse_mode,
+  Args,
+};
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>>
+{
+  let sets = list::<UnseenSetID>(args.get("set").unwrap_or("Top999WiktionaryFr"))?;
+  let seen_thresholds = list::<f64>(args.get("ratio").unwrap_or("0.4"))?
+    .into_iter()
+    .map(SeenThreshold::try_from)
+    .collect::<Result<Vec<_>, _>>()?;
+  let players = list::<Player>(
+    args
+      .get("player")
+      .unwrap_or("perfect,capacity:50,forgetting:40,biased:0.4"),
+  )?;
+
+  let mut summaries = Vec::new();
+  for unseen_set_id in sets {
+    let words = args.words(&unseen_set_id)?;
+    summaries.extend(
+      SimulationBuilder::default()
+        .unseen_set_id(unseen_set_id)
+        .seen_thresholds(seen_thresholds.clone())
+        .mode(parse_mode(args.get("mode").unwrap_or("classic"))?)
+        .players(players.clone())
+        .games(args.parse_or("games", 200)?)
+        .seed(args.parse_or("seed", 0)?)
+        .max_commits(args.parse_or("max-commits", 10000)?)
+        .build()?
+        .run(&words.iter().map(|x| x.as_str()).collect::<Vec<_>>())?,
+    );
+  }
+
+  if args.flag("json") {
+    println!(
+      "{}",
+      serde_json::to_string_pretty(&summaries.iter().map(summary_json).collect::<Value>())?
+    );
+  } else {
+    println!(
+      "{:<20} {:>5} {:<24} {:>6} {:>5} {:>5} {:>5} {:>5} {:>5} {:>7} {:>6} {:>5} {:>5}",
+      "set",
+      "ratio",
+      "player",
+      "mean",
+      "min",
+      "q1",
+      "med",
+      "q3",
+      "max",
+      "length",
+      "unfin",
+      "hit",
+      "fa"
+    );
+    for x in &summaries {
+      let (hit, fa) = rates(x);
+      println!(
+        "{:<20} {:>5.2} {:<24} {:>6.1} {:>5} {:>5} {:>5} {:>5} {:>5} {:>7.1} {:>6} {:>5.2} {:>5.2}",
+        format!("{:?}", x.unseen_set_id()),
+        x.seen_threshold().ratio(),
+        x.player().to_string(),
+        x.mean_score(),
+        x.score_quantile(0.0),
+        x.score_quantile(0.25),
+        x.score_quantile(0.5),
+        x.score_quantile(0.75),
+        x.score_quantile(1.0),
+        x.mean_length(),
+        x.unfinished(),
+        hit,
+        fa
+      );
+    }
+  }
+
+  Ok(())
+}
+
+/// Parses a comma separated list.
+fn list<T>(s: &str) -> Result<Vec<T>, Box<dyn Error>>
+where
+  T: std::str::FromStr,
+  T::Err: Error + 'static,
+{
+  s.split(',')
+    .map(|x| x.trim().parse().map_err(|e: T::Err| e.into()))
+    .collect()
+}
+
+/// The hit rate and the false alarm rate.
+fn rates(summary: &Summary) -> (f64, f64)
+{
+  let stats = summary.stats();
+  let rate = |x: usize, y: usize| x as f64 / (x + y).max(1) as f64;
+  (
+    rate(stats.hits(), stats.misses()),
+    rate(stats.false_alarms(), stats.correct_rejections()),
+  )
+}
+
+fn summary_json(summary: &Summary) -> Value
+{
+  let stats = summary.stats();
+  json!({
+    "unseen_set_id": summary.unseen_set_id(),
+    "seen_ratio": summary.seen_threshold().ratio(),
+    "player": summary.player().to_string(),
+    "games": summary.games(),
+    "unfinished": summary.unfinished(),
+    "scores": summary.scores(),
+    "lengths": summary.lengths(),
+    "mean_score": summary.mean_score(),
+    "mean_length": summary.mean_length(),
+    "hits": stats.hits(),
+    "misses": stats.misses(),
+    "false_alarms": stats.false_alarms(),
+    "correct_rejections": stats.correct_rejections(),
+  })
+}
@@ -1,14 +1,67 @@
+mod archive;
+mod challenge;
 mod coder;
 mod encoded_game_over;
+mod mac;
+mod registry;
+mod schema;
+mod share_layout;
 mod unseen_set_id;
-mod version;
 
+pub use archive::{
+  ArchiveError,
+  ArchiveHeader,
+  ArchiveReader,
+  ArchiveWriter,
+  ARCHIVE_VERSION,
+};
+pub use challenge::{
+  Challenge,
+  ChallengeBuilder,
+  ChallengeBuilderError,
+  ChallengeError,
+  SealedChallenge,
+  CHALLENGE_VERSION,
+};
 pub use coder::{
   GameOverCoderV01,
+  GameOverCoderV01Error,
+  GameOverCoderV02,
+  GameOverCoderV02Error,
+  GameOverCoderV03,
+  GameOverCoderV03Error,
+  GameOverCoderV04,
+  GameOverCoderV04Error,
+  LatestCoder,
   Version00Coding,
+  Version00CodingError,
 };
 pub use encoded_game_over::{
+  CoderChecksum,
+  CoderError,
+  CoderVersion,
+  DecodeGameOver,
+  EncodeGameOver,
   EncodedGameOver,
+  SealedEncodedError,
   SealedEncodedGameOver,
 };
-pub use unseen_set_id::UnseenSetID;
+pub use mac::{
+  Authenticity,
+  MacKey,
+  MacKeyError,
+};
+pub use registry::{
+  Coder,
+  CoderRegistry,
+  CoderRegistryError,
+};
+pub use schema::schemas;
+pub use share_layout::{
+  ShareLayout,
+  ShareLayoutError,
+};
+pub use unseen_set_id::{
+  UnseenSetID,
+  UnseenSetIDError,
+};
@@ -0,0 +1,324 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+  error::Error,
+  fmt::Display,
+  io::{
+    BufRead,
+    Lines,
+    Write,
+  },
+};
+
+use serde::{
+  Deserialize,
+  Serialize,
+};
+use serde_json::{
+  Map,
+  Value,
+};
+
+use crate::{
+  coder::{
+    EncodedGameOver,
+    SealedEncodedGameOver,
+    UnseenSetID,
+  },
+  rng::{
+    IndexedPermutation,
+    KSINK,
+  },
+};
+
+const SEED: u64 = 1469598103934665603;
+
+/// The version of the archive format.
+pub const ARCHIVE_VERSION: &str = "ar-v01";
+
+// -------------------------------------------------------------------------------------------------
+// Lines
+// -------------------------------------------------------------------------------------------------
+
+/// A line of an archive. An archive is JSON-lines starting with a `Header` and ending with a
+/// `Trailer`. Each unseen set is declared once with a `Set` line before the first `Game` that
+/// references it.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "line", rename_all = "snake_case")]
+enum Line
+{
+  Header
+  {
+    version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+  },
+  Set
+  {
+    set: usize, id: UnseenSetID
+  },
+  Game
+  {
+    set: usize,
+    #[serde(flatten)]
+    code: Map<String, Value>,
+  },
+  Trailer
+  {
+    count: usize, checksum: u64
+  },
+}
+
+// -------------------------------------------------------------------------------------------------
+// ArchiveHeader
+// -------------------------------------------------------------------------------------------------
+
+/// The header of an archive.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ArchiveHeader
+{
+  title: Option<String>,
+}
+
+impl ArchiveHeader
+{
+  pub fn new() -> ArchiveHeader
+  {
+    ArchiveHeader::default()
+  }
+
+  /// Names the archive, e.g. after the tournament it holds.
+  pub fn with_title(mut self, title: impl Into<String>) -> ArchiveHeader
+  {
+    self.title = Some(title.into());
+    self
+  }
+
+  pub fn title(&self) -> Option<&str>
+  {
+    self.title.as_deref()
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ArchiveWriter
+// -------------------------------------------------------------------------------------------------
+
+/// Writes games to an archive one at a time.
+pub struct ArchiveWriter<W: Write>
+{
+  writer: W,
+  sets: Vec<UnseenSetID>,
+  count: usize,
+  checksum: u64,
+}
+
+impl<W: Write> ArchiveWriter<W>
+{
+  /// Writes the header of the archive to `writer`.
+  pub fn new(writer: W, header: &ArchiveHeader) -> Result<ArchiveWriter<W>, Box<dyn Error>>
+  {
+    let mut archive = ArchiveWriter {
+      writer,
+      sets: Vec::new(),
+      count: 0,
+      checksum: SEED,
+    };
+    archive.write_line(&Line::Header {
+      version: ARCHIVE_VERSION.to_string(),
+      title: header.title.clone(),
+    })?;
+    Ok(archive)
+  }
+
+  /// Adds `sealed` to the archive.
+  pub fn write(&mut self, sealed: &SealedEncodedGameOver) -> Result<(), Box<dyn Error>>
+  {
+    let id = sealed.unseen_set_id();
+    let set = match self.sets.iter().position(|x| x == id) {
+      Some(set) => set,
+      None => {
+        self.sets.push(id.clone());
+        let set = self.sets.len() - 1;
+        self.write_line(&Line::Set {
+          set,
+          id: id.clone(),
+        })?;
+        set
+      }
+    };
+
+    let mut code = match serde_json::to_value(sealed)? {
+      Value::Object(code) => code,
+      _ => unreachable!(),
+    };
+    code.remove("unseen_set_id");
+    self.write_line(&Line::Game { set, code })?;
+    self.count += 1;
+    Ok(())
+  }
+
+  /// Writes the trailer and returns the underlying writer.
+  pub fn finish(mut self) -> Result<W, Box<dyn Error>>
+  {
+    let trailer = Line::Trailer {
+      count: self.count,
+      checksum: self.checksum,
+    };
+    self.write_line(&trailer)?;
+    self.writer.flush()?;
+    Ok(self.writer)
+  }
+
+  fn write_line(&mut self, line: &Line) -> Result<(), Box<dyn Error>>
+  {
+    let s = serde_json::to_string(line)?;
+    self.checksum = KSINK::hash(self.checksum, s.as_bytes());
+    writeln!(self.writer, "{}", s)?;
+    Ok(())
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ArchiveReader
+// -------------------------------------------------------------------------------------------------
+
+/// Reads the games of an archive one at a time. Every game is verified with
+/// `EncodedGameOver::try_from` as it is read, and the count and checksum of the archive when the
+/// trailer is reached. Iteration stops after the first error.
+pub struct ArchiveReader<R: BufRead>
+{
+  lines: Lines<R>,
+  header: ArchiveHeader,
+  sets: Vec<UnseenSetID>,
+  count: usize,
+  checksum: u64,
+  done: bool,
+}
+
+impl<R: BufRead> ArchiveReader<R>
+{
+  /// Reads the header of the archive from `reader`.
+  pub fn new(reader: R) -> Result<ArchiveReader<R>, Box<dyn Error>>
+  {
+    let mut archive = ArchiveReader {
+      lines: reader.lines(),
+      header: ArchiveHeader::default(),
+      sets: Vec::new(),
+      count: 0,
+      checksum: SEED,
+      done: false,
+    };
+    match archive.read_line()? {
+      Some(Line::Header { version, title }) if version == ARCHIVE_VERSION => {
+        archive.header = ArchiveHeader { title };
+        Ok(archive)
+      }
+      Some(Line::Header { version, .. }) => {
+        Err(Box::new(ArchiveError::UnrecognisedVersion(version)))
+      }
+      _ => Err(Box::new(ArchiveError::MissingHeader)),
+    }
+  }
+
+  pub fn header(&self) -> &ArchiveHeader
+  {
+    &self.header
+  }
+
+  fn read_line(&mut self) -> Result<Option<Line>, Box<dyn Error>>
+  {
+    match self.lines.next() {
+      None => Ok(None),
+      Some(s) => {
+        let s = s?;
+        let line = serde_json::from_str(&s)?;
+        if !matches!(line, Line::Trailer { .. }) {
+          self.checksum = KSINK::hash(self.checksum, s.as_bytes());
+        }
+        Ok(Some(line))
+      }
+    }
+  }
+
+  /// The next game, or `None` once the trailer has been verified.
+  fn read_game(&mut self) -> Result<Option<SealedEncodedGameOver>, Box<dyn Error>>
+  {
+    use ArchiveError::*;
+
+    loop {
+      match self.read_line()?.ok_or(MissingTrailer)? {
+        Line::Set { set, id } if set == self.sets.len() => self.sets.push(id),
+        Line::Set { set, .. } => return Err(Box::new(UnknownSet(set))),
+        Line::Game { set, mut code } => {
+          let id = self.sets.get(set).ok_or(UnknownSet(set))?;
+          code.insert("unseen_set_id".to_string(), serde_json::to_value(id)?);
+          let sealed: SealedEncodedGameOver = serde_json::from_value(Value::Object(code))?;
+          EncodedGameOver::try_from(sealed.clone())?;
+          self.count += 1;
+          return Ok(Some(sealed));
+        }
+        Line::Trailer { count, checksum } => {
+          if count != self.count || checksum != self.checksum {
+            return Err(Box::new(InvalidChecksum));
+          }
+          if self.lines.next().is_some() {
+            return Err(Box::new(UnexpectedLine));
+          }
+          return Ok(None);
+        }
+        Line::Header { .. } => return Err(Box::new(UnexpectedLine)),
+      }
+    }
+  }
+}
+
+impl<R: BufRead> Iterator for ArchiveReader<R>
+{
+  type Item = Result<SealedEncodedGameOver, Box<dyn Error>>;
+
+  fn next(&mut self) -> Option<Self::Item>
+  {
+    if self.done {
+      return None;
+    }
+    let game = self.read_game();
+    self.done = !matches!(game, Ok(Some(_)));
+    game.transpose()
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ArchiveError
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub enum ArchiveError
+{
+  UnrecognisedVersion(String),
+  MissingHeader,
+  MissingTrailer,
+  UnknownSet(usize),
+  UnexpectedLine,
+  InvalidChecksum,
+}
+
+impl Display for ArchiveError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use ArchiveError::*;
+
+    match self {
+      UnrecognisedVersion(s) => write!(f, "archive version '{}' is unrecognised", s),
+      MissingHeader => write!(f, "the archive has no header"),
+      MissingTrailer => write!(f, "the archive is truncated"),
+      UnknownSet(i) => write!(f, "unseen set {} is not declared", i),
+      UnexpectedLine => write!(f, "the archive has a line out of place"),
+      InvalidChecksum => write!(f, "the archive is corrupted"),
+    }
+  }
+}
+
+impl Error for ArchiveError {}
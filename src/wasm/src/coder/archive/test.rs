@@ -0,0 +1,118 @@
+use super::*;
+use crate::{
+  coder::{
+    GameOverCoderV04,
+    SealedEncodedError,
+  },
+  game::Game,
+  game_over::GameOver,
+};
+
+fn sealed(seed: u64, unseen_set_id: UnseenSetID) -> SealedEncodedGameOver
+{
+  let unseen: Vec<String> = (0..100).map(|i| format!("{:02}", i)).collect();
+  let mut game = Game::new(seed, 0.5.try_into().unwrap(), unseen_set_id, unseen);
+  while !game.finished() {
+    game.next().unwrap();
+    let seen = game.is_current_seen().unwrap();
+    game
+      .commit_with(seen ^ (game.commit_count() % 6 == 5), None)
+      .unwrap();
+  }
+  SealedEncodedGameOver::new::<GameOverCoderV04, _>(&GameOver::from(game)).unwrap()
+}
+
+fn games() -> Vec<SealedEncodedGameOver>
+{
+  vec![
+    sealed(1, UnseenSetID::Top999WiktionaryFr),
+    sealed(2, UnseenSetID::DictionaryFr01).with_challenge(7),
+    sealed(3, UnseenSetID::Top999WiktionaryFr),
+  ]
+}
+
+fn archive(games: &[SealedEncodedGameOver]) -> String
+{
+  let header = ArchiveHeader::new().with_title("Finals");
+  let mut writer = ArchiveWriter::new(Vec::new(), &header).unwrap();
+  for game in games {
+    writer.write(game).unwrap();
+  }
+  String::from_utf8(writer.finish().unwrap()).unwrap()
+}
+
+fn read(s: &str) -> Result<Vec<SealedEncodedGameOver>, Box<dyn Error>>
+{
+  ArchiveReader::new(s.as_bytes())?.collect()
+}
+
+#[test]
+fn archive_round_trip()
+{
+  let games = games();
+  let s = archive(&games);
+
+  // Each unseen set is declared once.
+  assert_eq!(s.lines().count(), 1 + 2 + 3 + 1);
+  assert_eq!(s.matches("Top999WiktionaryFr").count(), 1);
+
+  let reader = ArchiveReader::new(s.as_bytes()).unwrap();
+  assert_eq!(reader.header().title(), Some("Finals"));
+  let games_read: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+  assert_eq!(games_read.len(), games.len());
+  for (x, y) in games_read.iter().zip(games.iter()) {
+    assert_eq!(
+      serde_json::to_value(x).unwrap(),
+      serde_json::to_value(y).unwrap()
+    );
+  }
+
+  assert!(read(&archive(&[])).unwrap().is_empty());
+}
+
+#[test]
+fn corrupted_archives_are_rejected()
+{
+  let s = archive(&games());
+  let lines: Vec<&str> = s.lines().collect();
+
+  // A missing game.
+  assert!(lines[5].contains("\"line\":\"game\""));
+  let missing = [&lines[..5], &lines[6..]].concat().join("\n");
+  assert_eq!(
+    read(&missing).unwrap_err().downcast_ref(),
+    Some(&ArchiveError::InvalidChecksum)
+  );
+
+  // A truncated archive.
+  assert_eq!(
+    read(&lines[..lines.len() - 1].join("\n"))
+      .unwrap_err()
+      .downcast_ref(),
+    Some(&ArchiveError::MissingTrailer)
+  );
+
+  // A game with a bad checksum is rejected even if the archive checksum is recomputed.
+  let checksum = lines[2].split("\"checksum\":").nth(1).unwrap();
+  let checksum: u64 = checksum[..checksum.find(',').unwrap()].parse().unwrap();
+  let tampered = s.replacen(
+    &format!("\"checksum\":{}", checksum),
+    &format!("\"checksum\":{}", checksum ^ 1),
+    1,
+  );
+  let mut writer = ArchiveWriter::new(Vec::new(), &ArchiveHeader::new()).unwrap();
+  for line in tampered.lines().skip(1).filter(|x| !x.contains("trailer")) {
+    writer
+      .write_line(&serde_json::from_str(line).unwrap())
+      .unwrap();
+  }
+  writer.count = 3;
+  let tampered = String::from_utf8(writer.finish().unwrap()).unwrap();
+  assert_eq!(
+    read(&tampered).unwrap_err().downcast_ref(),
+    Some(&SealedEncodedError::InvalidChecksum)
+  );
+
+  // Not an archive.
+  assert!(ArchiveReader::new("{}".as_bytes()).is_err());
+}
@@ -0,0 +1,256 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+  error::Error,
+  fmt::Display,
+  str::FromStr,
+};
+
+use serde::{
+  Deserialize,
+  Serialize,
+};
+use url::Url;
+
+use crate::{
+  coder::{
+    encoded_game_over::SealedEncodedError,
+    SealedEncodedGameOver,
+    UnseenSetID,
+  },
+  game::{
+    Game,
+    GameMode,
+    SeenThreshold,
+    INITIAL_LIVES_AMOUNT,
+  },
+  game_over::GameOver,
+  rng::{
+    IndexedPermutation,
+    KSINK,
+  },
+};
+
+const SEED: u64 = 5924410372541962749;
+
+/// The version of the encoding of a `SealedChallenge`.
+pub const CHALLENGE_VERSION: &str = "ch-v01";
+
+// -------------------------------------------------------------------------------------------------
+// Challenge
+// -------------------------------------------------------------------------------------------------
+
+/// The settings of a game without any results. Anyone with the same elements can start exactly
+/// the same game from it.
+#[derive(Builder, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Challenge
+{
+  seed: u64,
+  seen_threshold: SeenThreshold,
+  unseen_set_id: UnseenSetID,
+
+  #[builder(default = "INITIAL_LIVES_AMOUNT")]
+  lives: usize,
+
+  #[builder(default)]
+  mode: GameMode,
+}
+
+impl Challenge
+{
+  pub fn seed(&self) -> u64
+  {
+    self.seed
+  }
+
+  pub fn seen_threshold(&self) -> SeenThreshold
+  {
+    self.seen_threshold
+  }
+
+  pub fn unseen_set_id(&self) -> &UnseenSetID
+  {
+    &self.unseen_set_id
+  }
+
+  /// The amount of lives the game starts with.
+  pub fn lives(&self) -> usize
+  {
+    self.lives
+  }
+
+  pub fn mode(&self) -> &GameMode
+  {
+    &self.mode
+  }
+
+  /// Starts the game of the challenge with elements from `unseen`.
+  pub fn game<T>(&self, unseen: Vec<T>) -> Game<T>
+  {
+    Game::with_mode(
+      self.seed,
+      self.seen_threshold,
+      self.unseen_set_id.clone(),
+      unseen,
+      self.mode.clone(),
+    )
+    .with_lives(self.lives)
+  }
+
+  /// True if `game_over` was played with the settings of the challenge.
+  pub fn matches<T>(&self, game_over: &GameOver<T>) -> bool
+  {
+    self.seed == game_over.seed()
+      && self.seen_threshold == game_over.seen_threshold()
+      && &self.unseen_set_id == game_over.unseen_set_id()
+      && self.lives == game_over.initial_lives()
+      && &self.mode == game_over.mode()
+  }
+}
+
+impl<T> From<&GameOver<T>> for Challenge
+{
+  /// The challenge of playing the same game as `game_over`.
+  fn from(game_over: &GameOver<T>) -> Self
+  {
+    Challenge {
+      seed: game_over.seed(),
+      seen_threshold: game_over.seen_threshold(),
+      unseen_set_id: game_over.unseen_set_id().clone(),
+      lives: game_over.initial_lives(),
+      mode: game_over.mode().clone(),
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// SealedChallenge
+// -------------------------------------------------------------------------------------------------
+
+/// An encoded `Challenge` that can be shared as a URL search query. Its checksum identifies the
+/// challenge and is what the results of it reference.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SealedChallenge
+{
+  version: String,
+  checksum: u64,
+  data: String,
+}
+
+impl SealedChallenge
+{
+  pub fn new(challenge: &Challenge) -> Result<SealedChallenge, Box<dyn Error>>
+  {
+    let data = base64::encode(serde_json::to_string(challenge)?);
+    Ok(SealedChallenge {
+      version: CHALLENGE_VERSION.to_string(),
+      checksum: KSINK::hash(SEED, data.as_bytes()),
+      data,
+    })
+  }
+
+  /// The ID that results of the challenge reference.
+  pub fn id(&self) -> u64
+  {
+    self.checksum
+  }
+
+  /// The version of the encoding.
+  pub fn version(&self) -> &str
+  {
+    &self.version
+  }
+
+  /// Verifies and decodes the challenge.
+  pub fn open(&self) -> Result<Challenge, Box<dyn Error>>
+  {
+    if self.version != CHALLENGE_VERSION {
+      return Err(Box::new(ChallengeError::UnrecognisedVersion(
+        self.version.clone(),
+      )));
+    }
+    if self.checksum != KSINK::hash(SEED, self.data.as_bytes()) {
+      return Err(Box::new(SealedEncodedError::InvalidChecksum));
+    }
+    Ok(serde_json::from_slice(&base64::decode(&self.data)?)?)
+  }
+
+  /// Marks `sealed` as a result of the challenge.
+  pub fn sign(&self, sealed: SealedEncodedGameOver) -> SealedEncodedGameOver
+  {
+    sealed.with_challenge(self.id())
+  }
+
+  /// Decodes a result of the challenge. Throws if `sealed` does not reference the challenge or if
+  /// the game was not played with its settings.
+  pub fn result<T>(
+    &self,
+    sealed: SealedEncodedGameOver,
+    unseen: Vec<T>,
+  ) -> Result<GameOver<T>, Box<dyn Error>>
+  where
+    T: Clone + PartialEq + AsRef<[u8]>,
+  {
+    if sealed.challenge() != Some(self.id()) {
+      return Err(Box::new(ChallengeError::NotReferenced));
+    }
+    let challenge = self.open()?;
+    let game_over = GameOver::try_from((sealed, unseen))?;
+    if challenge.matches(&game_over) {
+      Ok(game_over)
+    } else {
+      Err(Box::new(ChallengeError::SettingsMismatch))
+    }
+  }
+}
+
+impl FromStr for SealedChallenge
+{
+  type Err = Box<dyn Error>;
+
+  /// Parses either a challenge URL or its search query, with or without the leading '?'.
+  fn from_str(s: &str) -> Result<Self, Self::Err>
+  {
+    let s = s.trim();
+    match Url::parse(s) {
+      Ok(url) => Ok(serde_urlencoded::from_str(
+        url.query().ok_or(SealedEncodedError::MissingQuery)?,
+      )?),
+      Err(_) => Ok(serde_urlencoded::from_str(
+        s.strip_prefix('?').unwrap_or(s),
+      )?),
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ChallengeError
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub enum ChallengeError
+{
+  UnrecognisedVersion(String),
+  NotReferenced,
+  SettingsMismatch,
+}
+
+impl Display for ChallengeError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use ChallengeError::*;
+
+    match self {
+      UnrecognisedVersion(s) => write!(f, "challenge version '{}' is unrecognised", s),
+      NotReferenced => write!(f, "the result does not reference the challenge"),
+      SettingsMismatch => write!(
+        f,
+        "the result was not played with the settings of the challenge"
+      ),
+    }
+  }
+}
+
+impl Error for ChallengeError {}
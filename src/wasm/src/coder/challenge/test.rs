@@ -0,0 +1,88 @@
+use super::*;
+use crate::coder::LatestCoder;
+
+fn unseen() -> Vec<String>
+{
+  (0..200).map(|i| format!("{:03}", i)).collect()
+}
+
+fn play(challenge: &Challenge, wrong_every: usize) -> GameOver<String>
+{
+  let mut game = challenge.game(unseen());
+  let mut i = 0;
+  while !game.finished() {
+    game.next().unwrap();
+    let seen = game.is_current_seen().unwrap();
+    i += 1;
+    game
+      .commit_with(seen ^ (i % wrong_every == 0), None)
+      .unwrap();
+  }
+  GameOver::from(game)
+}
+
+fn challenge() -> Challenge
+{
+  ChallengeBuilder::default()
+    .seed(1234)
+    .seen_threshold(0.4.try_into().unwrap())
+    .unseen_set_id(UnseenSetID::Unspecified)
+    .lives(5)
+    .mode(GameMode::Window(8))
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn challenge_survives_a_url_round_trip()
+{
+  let sealed = SealedChallenge::new(&challenge()).unwrap();
+  let query = serde_urlencoded::to_string(&sealed).unwrap();
+  let parsed: SealedChallenge = format!("https://example.com/challenge/?{}", query)
+    .parse()
+    .unwrap();
+  assert_eq!(parsed, sealed);
+  assert_eq!(parsed.open().unwrap(), challenge());
+}
+
+#[test]
+fn tampered_challenge_is_rejected()
+{
+  let mut sealed = SealedChallenge::new(&challenge()).unwrap();
+  sealed.checksum ^= 1;
+  assert!(sealed.open().is_err());
+}
+
+#[test]
+fn results_reference_the_challenge()
+{
+  let challenge = challenge();
+  let sealed = SealedChallenge::new(&challenge).unwrap();
+
+  let game_over = play(&challenge, 7);
+  assert_eq!(game_over.initial_lives(), 5);
+  assert_eq!(game_over.commit_count(), 35);
+  let result = sealed.sign(SealedEncodedGameOver::new::<LatestCoder, _>(&game_over).unwrap());
+
+  // The reference survives the share URL.
+  let result: SealedEncodedGameOver = serde_urlencoded::to_string(&result)
+    .unwrap()
+    .parse()
+    .unwrap();
+  assert_eq!(result.challenge(), Some(sealed.id()));
+  assert!(sealed.result(result, unseen()).unwrap() == game_over);
+
+  // A result without a reference is not accepted.
+  let unsigned = SealedEncodedGameOver::new::<LatestCoder, _>(&game_over).unwrap();
+  assert!(sealed.result(unsigned, unseen()).is_err());
+
+  // Neither is a result of another game that references the challenge.
+  let other = ChallengeBuilder::default()
+    .seed(4321)
+    .seen_threshold(0.4.try_into().unwrap())
+    .unseen_set_id(UnseenSetID::Unspecified)
+    .build()
+    .unwrap();
+  let forged = sealed.sign(SealedEncodedGameOver::new::<LatestCoder, _>(&play(&other, 7)).unwrap());
+  assert!(sealed.result(forged, unseen()).is_err());
+}
@@ -1,8 +1,33 @@
 mod game_over_coder_v01;
+mod game_over_coder_v02;
+mod game_over_coder_v03;
+mod game_over_coder_v04;
 mod version00coding;
 
+pub(crate) use game_over_coder_v01::GameOverCoderV01Data;
 pub use game_over_coder_v01::{
   GameOverCoderV01,
   GameOverCoderV01Error,
 };
-pub use version00coding::Version00Coding;
+pub(crate) use game_over_coder_v02::GameOverCoderV02Data;
+pub use game_over_coder_v02::{
+  GameOverCoderV02,
+  GameOverCoderV02Error,
+};
+pub(crate) use game_over_coder_v03::GameOverCoderV03Data;
+pub use game_over_coder_v03::{
+  GameOverCoderV03,
+  GameOverCoderV03Error,
+};
+pub(crate) use game_over_coder_v04::GameOverCoderV04Data;
+pub use game_over_coder_v04::{
+  GameOverCoderV04,
+  GameOverCoderV04Error,
+};
+pub use version00coding::{
+  Version00Coding,
+  Version00CodingError,
+};
+
+/// The coder new share codes are encoded with.
+pub type LatestCoder = GameOverCoderV04;
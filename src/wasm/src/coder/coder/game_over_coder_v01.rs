@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use schemars::JsonSchema;
 use serde::{
   Deserialize,
   Serialize,
@@ -9,16 +10,17 @@ use crate::{
   coder::{
     encoded_game_over::{
       CoderChecksum,
+      CoderError,
       CoderVersion,
       DecodeGameOver,
       EncodeGameOver,
     },
     unseen_set_id::UnseenSetID,
-    version::GameOverCoderVersion,
   },
   game::{
-    IncorrectCommits,
+    GameMode,
     SeenThreshold,
+    INITIAL_LIVES_AMOUNT,
   },
   game_over::GameOver,
   rng::{
@@ -38,9 +40,9 @@ pub struct GameOverCoderV01;
 
 impl CoderVersion for GameOverCoderV01
 {
-  fn version() -> GameOverCoderVersion
+  fn version() -> &'static str
   {
-    GameOverCoderVersion::GameOverCoderV01
+    "goc-v01"
   }
 }
 
@@ -58,11 +60,17 @@ impl<T> EncodeGameOver<T> for GameOverCoderV01
 
   fn encode(game_over: &GameOver<T>) -> Result<String, Self::Error>
   {
+    if game_over.mode() != &GameMode::Classic {
+      return Err(Box::new(GameOverCoderV01Error::UnsupportedMode));
+    }
     Ok(base64::encode(&serde_json::to_string(
       &GameOverCoderV01Data {
         seed: game_over.seed(),
         seen_threshold: game_over.seen_threshold(),
-        incorrect_commits: game_over.incorrect_commits(),
+        incorrect_commits: game_over
+          .incorrect_commits()
+          .try_into()
+          .map_err(|_| GameOverCoderV01Error::UnsupportedLives(game_over.initial_lives()))?,
         element_checksum: game_over.element_checksum(),
       },
     )?))
@@ -88,10 +96,10 @@ where
       unseen_set_id,
       unseen,
       data.seen_threshold,
-      data.incorrect_commits,
-    );
+      data.incorrect_commits.to_vec(),
+    )?;
     if data.element_checksum != game_over.element_checksum() {
-      Err(Box::new(GameOverCoderV01Error::BadElementChecksum))
+      Err(Box::new(CoderError::BadElementChecksum))
     } else {
       Ok(game_over)
     }
@@ -102,12 +110,14 @@ where
 // Encoded data
 // -------------------------------------------------------------------------------------------------
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GameOverCoderV01Data
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct GameOverCoderV01Data
 {
   seed: u64,
   seen_threshold: SeenThreshold,
-  incorrect_commits: IncorrectCommits,
+  /// The index of the commit each of the three lives was lost at, if it was. Games started with
+  /// another amount of lives need a coder that stores it.
+  incorrect_commits: [Option<usize>; INITIAL_LIVES_AMOUNT],
   element_checksum: u64,
 }
 
@@ -119,7 +129,8 @@ struct GameOverCoderV01Data
 #[derive(Debug)]
 pub enum GameOverCoderV01Error
 {
-  BadElementChecksum,
+  UnsupportedLives(usize),
+  UnsupportedMode,
 }
 
 impl Display for GameOverCoderV01Error
@@ -128,7 +139,12 @@ impl Display for GameOverCoderV01Error
   {
     use GameOverCoderV01Error::*;
     match self {
-      BadElementChecksum => writeln!(f, "element checksum does not match the accumelated"),
+      UnsupportedLives(lives) => writeln!(
+        f,
+        "only games started with {} lives can be encoded, not {}",
+        INITIAL_LIVES_AMOUNT, lives
+      ),
+      UnsupportedMode => writeln!(f, "only games played in classic mode can be encoded"),
     }
   }
 }
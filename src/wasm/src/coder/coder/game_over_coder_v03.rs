@@ -0,0 +1,212 @@
+use std::fmt::Display;
+
+use schemars::JsonSchema;
+use serde::{
+  Deserialize,
+  Serialize,
+};
+
+use crate::{
+  coder::{
+    encoded_game_over::{
+      CoderChecksum,
+      CoderError,
+      CoderVersion,
+      DecodeGameOver,
+      EncodeGameOver,
+    },
+    unseen_set_id::UnseenSetID,
+  },
+  game::{
+    Confidence,
+    GameMode,
+    SeenThreshold,
+    INITIAL_LIVES_AMOUNT,
+  },
+  game_over::GameOver,
+  rng::{
+    IndexedPermutation,
+    KSINK,
+  },
+};
+
+const SEED: u64 = 2866135187398431027;
+
+/// Coder that extends `GameOverCoderV02` with the reaction times and the commit checksum of the
+/// game. Decoding replays the game and fails unless it reproduces the commit checksum.
+pub struct GameOverCoderV03;
+
+// -------------------------------------------------------------------------------------------------
+// Coder implementations
+// -------------------------------------------------------------------------------------------------
+
+impl CoderVersion for GameOverCoderV03
+{
+  fn version() -> &'static str
+  {
+    "goc-v03"
+  }
+}
+
+impl CoderChecksum for GameOverCoderV03
+{
+  fn checksum(data: &[u8]) -> u64
+  {
+    KSINK::hash(SEED, data)
+  }
+}
+
+impl<T> EncodeGameOver<T> for GameOverCoderV03
+{
+  type Error = Box<dyn std::error::Error>;
+
+  fn encode(game_over: &GameOver<T>) -> Result<String, Self::Error>
+  {
+    let reaction_times = game_over.reaction_times();
+    Ok(base64::encode(serde_json::to_string(
+      &GameOverCoderV03Data {
+        seed: game_over.seed(),
+        seen_threshold: game_over.seen_threshold(),
+        mode: game_over.mode().clone(),
+        incorrect_commits: game_over
+          .incorrect_commits()
+          .try_into()
+          .map_err(|_| GameOverCoderV03Error::UnsupportedLives(game_over.initial_lives()))?,
+        element_checksum: game_over.element_checksum(),
+        commit_checksum: game_over.commit_checksum(),
+        confidences: encode_confidences(game_over.confidences()),
+        reaction_times: reaction_times
+          .iter()
+          .any(|x| x.is_some())
+          .then(|| reaction_times.to_vec()),
+      },
+    )?))
+  }
+}
+
+impl<T> DecodeGameOver<T> for GameOverCoderV03
+where
+  T: PartialEq + Clone + AsRef<[u8]>,
+{
+  type Error = Box<dyn std::error::Error>;
+
+  const COMMIT_CHECKSUM: bool = true;
+
+  fn decode(
+    data: String,
+    unseen_set_id: UnseenSetID,
+    unseen: Vec<T>,
+  ) -> Result<GameOver<T>, Self::Error>
+  {
+    use GameOverCoderV03Error::*;
+
+    let data = base64::decode(data)?;
+    let data: GameOverCoderV03Data = serde_json::from_slice(&data)?;
+    let mut game_over = GameOver::with_mode(
+      data.seed,
+      unseen_set_id,
+      unseen,
+      data.seen_threshold,
+      data.mode,
+      data.incorrect_commits.to_vec(),
+    )?;
+    if data.element_checksum != game_over.element_checksum() {
+      return Err(Box::new(CoderError::BadElementChecksum));
+    }
+    if let Some(s) = data.confidences {
+      let confidences = decode_confidences(&s)
+        .filter(|x| x.len() == game_over.commit_count())
+        .ok_or(BadConfidences)?;
+      game_over = game_over.with_confidences(confidences);
+    }
+    if let Some(reaction_times) = data.reaction_times {
+      if reaction_times.len() != game_over.commit_count() {
+        return Err(Box::new(BadReactionTimes));
+      }
+      game_over = game_over.with_reaction_times(reaction_times);
+    }
+    if data.commit_checksum != game_over.commit_checksum() {
+      return Err(Box::new(CoderError::BadCommitChecksum));
+    }
+    Ok(game_over)
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Encoded data
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct GameOverCoderV03Data
+{
+  seed: u64,
+  seen_threshold: SeenThreshold,
+  mode: GameMode,
+  /// The index of the commit each of the three lives was lost at, if it was. Games started with
+  /// another amount of lives need a coder that stores it.
+  incorrect_commits: [Option<usize>; INITIAL_LIVES_AMOUNT],
+  element_checksum: u64,
+  commit_checksum: u64,
+
+  /// One digit per commit where 0 is a commit without confidence. Left out if no commit has a
+  /// confidence.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  confidences: Option<String>,
+
+  /// Milliseconds per commit. Left out if no commit has a reaction time.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  reaction_times: Option<Vec<Option<u32>>>,
+}
+
+pub(super) fn encode_confidences(confidences: &[Option<Confidence>]) -> Option<String>
+{
+  confidences.iter().any(|x| x.is_some()).then(|| {
+    confidences
+      .iter()
+      .map(|x| char::from(b'0' + x.map_or(0, u8::from)))
+      .collect()
+  })
+}
+
+pub(super) fn decode_confidences(s: &str) -> Option<Vec<Option<Confidence>>>
+{
+  s.bytes()
+    .map(|x| match x {
+      b'0' => Some(None),
+      b'1'..=b'3' => (x - b'0').try_into().ok().map(Some),
+      _ => None,
+    })
+    .collect()
+}
+
+// -------------------------------------------------------------------------------------------------
+// Error
+// -------------------------------------------------------------------------------------------------
+
+/// Errors thrown when encoding/decoding with `GameOverCoderV03` fails.
+#[derive(Debug)]
+pub enum GameOverCoderV03Error
+{
+  UnsupportedLives(usize),
+  BadConfidences,
+  BadReactionTimes,
+}
+
+impl Display for GameOverCoderV03Error
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use GameOverCoderV03Error::*;
+    match self {
+      UnsupportedLives(lives) => writeln!(
+        f,
+        "only games started with {} lives can be encoded, not {}",
+        INITIAL_LIVES_AMOUNT, lives
+      ),
+      BadConfidences => writeln!(f, "confidences does not match the commits"),
+      BadReactionTimes => writeln!(f, "reaction times does not match the commits"),
+    }
+  }
+}
+
+impl std::error::Error for GameOverCoderV03Error {}
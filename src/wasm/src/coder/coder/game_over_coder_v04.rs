@@ -0,0 +1,228 @@
+use std::fmt::Display;
+
+use schemars::JsonSchema;
+use serde::{
+  Deserialize,
+  Serialize,
+};
+
+use super::game_over_coder_v03::{
+  decode_confidences,
+  encode_confidences,
+};
+use crate::{
+  coder::{
+    encoded_game_over::{
+      CoderChecksum,
+      CoderError,
+      CoderVersion,
+      DecodeGameOver,
+      EncodeGameOver,
+    },
+    unseen_set_id::UnseenSetID,
+  },
+  game::{
+    Game,
+    GameMode,
+    SeenThreshold,
+    MAX_LIVES,
+  },
+  game_over::GameOver,
+  rng::{
+    IndexedPermutation,
+    KSINK,
+  },
+};
+
+const SEED: u64 = 6417958722040318491;
+
+/// Coder that stores the guess of every commit instead of the incorrect commits. Decoding replays
+/// the guesses, so it does not rely on the game being determined by which commits were incorrect.
+pub struct GameOverCoderV04;
+
+// -------------------------------------------------------------------------------------------------
+// Coder implementations
+// -------------------------------------------------------------------------------------------------
+
+impl CoderVersion for GameOverCoderV04
+{
+  fn version() -> &'static str
+  {
+    "goc-v04"
+  }
+}
+
+impl CoderChecksum for GameOverCoderV04
+{
+  fn checksum(data: &[u8]) -> u64
+  {
+    KSINK::hash(SEED, data)
+  }
+}
+
+impl<T> EncodeGameOver<T> for GameOverCoderV04
+{
+  type Error = Box<dyn std::error::Error>;
+
+  fn encode(game_over: &GameOver<T>) -> Result<String, Self::Error>
+  {
+    let reaction_times = game_over.reaction_times();
+    Ok(base64::encode(serde_json::to_string(
+      &GameOverCoderV04Data {
+        seed: game_over.seed(),
+        seen_threshold: game_over.seen_threshold(),
+        mode: game_over.mode().clone(),
+        lives: game_over.initial_lives(),
+        commit_count: game_over.commit_count(),
+        guesses: encode_guesses(game_over.guesses()),
+        element_checksum: game_over.element_checksum(),
+        commit_checksum: game_over.commit_checksum(),
+        confidences: encode_confidences(game_over.confidences()),
+        reaction_times: reaction_times
+          .iter()
+          .any(|x| x.is_some())
+          .then(|| reaction_times.to_vec()),
+      },
+    )?))
+  }
+}
+
+impl<T> DecodeGameOver<T> for GameOverCoderV04
+where
+  T: PartialEq + Clone + AsRef<[u8]>,
+{
+  type Error = Box<dyn std::error::Error>;
+
+  const COMMIT_CHECKSUM: bool = true;
+
+  fn decode(
+    data: String,
+    unseen_set_id: UnseenSetID,
+    unseen: Vec<T>,
+  ) -> Result<GameOver<T>, Self::Error>
+  {
+    use GameOverCoderV04Error::*;
+
+    let data = base64::decode(data)?;
+    let data: GameOverCoderV04Data = serde_json::from_slice(&data)?;
+    if !(1..=MAX_LIVES).contains(&data.lives) {
+      return Err(Box::new(BadLives(data.lives)));
+    }
+    // A game runs out of lives long before it has made as many commits as there are unseen
+    // elements, so a larger count is forged. Checked before the guesses are allocated.
+    if data.commit_count > unseen.len() {
+      return Err(Box::new(BadGuesses));
+    }
+    let guesses = decode_guesses(&data.guesses, data.commit_count).ok_or(BadGuesses)?;
+    let game = Game::with_mode(
+      data.seed,
+      data.seen_threshold,
+      unseen_set_id,
+      unseen,
+      data.mode,
+    )
+    .with_lives(data.lives);
+    let mut game_over = GameOver::with_guesses(game, guesses).map_err(|_| BadGuesses)?;
+    if game_over.commit_count() != data.commit_count {
+      return Err(Box::new(BadGuesses));
+    }
+    if data.element_checksum != game_over.element_checksum() {
+      return Err(Box::new(CoderError::BadElementChecksum));
+    }
+    if let Some(s) = data.confidences {
+      let confidences = decode_confidences(&s)
+        .filter(|x| x.len() == game_over.commit_count())
+        .ok_or(BadConfidences)?;
+      game_over = game_over.with_confidences(confidences);
+    }
+    if let Some(reaction_times) = data.reaction_times {
+      if reaction_times.len() != game_over.commit_count() {
+        return Err(Box::new(BadReactionTimes));
+      }
+      game_over = game_over.with_reaction_times(reaction_times);
+    }
+    if data.commit_checksum != game_over.commit_checksum() {
+      return Err(Box::new(CoderError::BadCommitChecksum));
+    }
+    Ok(game_over)
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Encoded data
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct GameOverCoderV04Data
+{
+  seed: u64,
+  seen_threshold: SeenThreshold,
+  mode: GameMode,
+  lives: usize,
+  commit_count: usize,
+
+  /// Base64 of one bit per commit, set for a guess of seen, starting with the lowest bit of the
+  /// first byte.
+  guesses: String,
+
+  element_checksum: u64,
+  commit_checksum: u64,
+
+  /// One digit per commit where 0 is a commit without confidence. Left out if no commit has a
+  /// confidence.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  confidences: Option<String>,
+
+  /// Milliseconds per commit. Left out if no commit has a reaction time.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  reaction_times: Option<Vec<Option<u32>>>,
+}
+
+fn encode_guesses(guesses: &[bool]) -> String
+{
+  let mut bytes = vec![0u8; guesses.len().div_ceil(8)];
+  for (i, _) in guesses.iter().enumerate().filter(|(_, x)| **x) {
+    bytes[i / 8] |= 1 << (i % 8);
+  }
+  base64::encode(bytes)
+}
+
+fn decode_guesses(s: &str, commit_count: usize) -> Option<Vec<bool>>
+{
+  let bytes = base64::decode(s).ok()?;
+  (bytes.len() == commit_count.div_ceil(8)).then(|| {
+    (0..commit_count)
+      .map(|i| bytes[i / 8] & (1 << (i % 8)) != 0)
+      .collect()
+  })
+}
+
+// -------------------------------------------------------------------------------------------------
+// Error
+// -------------------------------------------------------------------------------------------------
+
+/// Errors thrown when encoding/decoding with `GameOverCoderV04` fails.
+#[derive(Debug)]
+pub enum GameOverCoderV04Error
+{
+  BadLives(usize),
+  BadGuesses,
+  BadConfidences,
+  BadReactionTimes,
+}
+
+impl Display for GameOverCoderV04Error
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use GameOverCoderV04Error::*;
+    match self {
+      BadLives(lives) => writeln!(f, "{} lives is not between 1 and {}", lives, MAX_LIVES),
+      BadGuesses => writeln!(f, "guesses does not match the commits"),
+      BadConfidences => writeln!(f, "confidences does not match the commits"),
+      BadReactionTimes => writeln!(f, "reaction times does not match the commits"),
+    }
+  }
+}
+
+impl std::error::Error for GameOverCoderV04Error {}
@@ -1,5 +1,9 @@
-use std::error::Error;
+use std::{
+  error::Error,
+  fmt::Display,
+};
 
+use schemars::JsonSchema;
 use serde::{
   Deserialize,
   Serialize,
@@ -14,8 +18,8 @@ use crate::{
       EncodeGameOver,
     },
     unseen_set_id::UnseenSetID,
-    version::GameOverCoderVersion,
   },
+  game::INITIAL_LIVES_AMOUNT,
   game_over::GameOver,
   rng::{
     IndexedPermutation,
@@ -26,7 +30,7 @@ use crate::{
 const SEED: u64 = 4997987866499591411;
 
 /// `Version00Coding` is a simple coding format that can restore played games.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
 pub struct Version00Coding
 {
   unseen_id: UnseenSetID,
@@ -40,9 +44,9 @@ pub struct Version00Coding
 
 impl CoderVersion for Version00Coding
 {
-  fn version() -> GameOverCoderVersion
+  fn version() -> &'static str
   {
-    GameOverCoderVersion::Version00Coding
+    "00"
   }
 }
 
@@ -60,14 +64,17 @@ impl<T> EncodeGameOver<T> for Version00Coding
 
   fn encode(game_over: &GameOver<T>) -> Result<String, Self::Error>
   {
+    // Only a game that lost all of its three lives is restored by its incorrect commits.
+    let incorrect_commits = game_over
+      .incorrect_commits()
+      .into_iter()
+      .collect::<Option<Vec<usize>>>()
+      .filter(|x| x.len() == INITIAL_LIVES_AMOUNT)
+      .ok_or(Version00CodingError::UnsupportedGame)?;
     Ok(base64::encode(serde_json::to_string(&Self {
       unseen_id: UnseenSetID::DictionaryFr01,
       seed: game_over.seed(),
-      incorrect_commits: vec![
-        game_over.incorrect_commits()[0].unwrap(),
-        game_over.incorrect_commits()[1].unwrap(),
-        game_over.incorrect_commits()[2].unwrap(),
-      ],
+      incorrect_commits,
     })?))
   }
 }
@@ -78,6 +85,8 @@ where
 {
   type Error = Box<dyn Error>;
 
+  const ELEMENT_CHECKSUM: bool = false;
+
   fn decode(
     data: String,
     _unseen_set_id: UnseenSetID,
@@ -85,16 +94,45 @@ where
   ) -> Result<GameOver<T>, Self::Error>
   {
     let decoded: Version00Coding = serde_json::from_slice(base64::decode(data)?.as_slice())?;
+    if decoded.incorrect_commits.len() != INITIAL_LIVES_AMOUNT {
+      return Err(Box::new(Version00CodingError::BadIncorrectCommits));
+    }
     Ok(GameOver::new(
       decoded.seed,
       UnseenSetID::DictionaryFr01,
       unseen,
       0.4.try_into()?,
-      [
-        Some(decoded.incorrect_commits[0]),
-        Some(decoded.incorrect_commits[1]),
-        Some(decoded.incorrect_commits[2]),
-      ],
-    ))
+      decoded.incorrect_commits.into_iter().map(Some).collect(),
+    )?)
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Error
+// -------------------------------------------------------------------------------------------------
+
+/// Errors thrown when encoding/decoding with `Version00Coding` fails.
+#[derive(Debug, PartialEq)]
+pub enum Version00CodingError
+{
+  UnsupportedGame,
+  BadIncorrectCommits,
+}
+
+impl Display for Version00CodingError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use Version00CodingError::*;
+    match self {
+      UnsupportedGame => writeln!(
+        f,
+        "only games that lost all of their {} lives can be encoded",
+        INITIAL_LIVES_AMOUNT
+      ),
+      BadIncorrectCommits => writeln!(f, "incorrect commits does not match the lives"),
+    }
   }
 }
+
+impl Error for Version00CodingError {}
@@ -4,32 +4,43 @@ mod test;
 use std::{
   error::Error,
   fmt::Display,
+  str::FromStr,
 };
 
+use schemars::JsonSchema;
 use serde::{
   Deserialize,
   Serialize,
 };
+use url::Url;
 
 use super::{
-  version::GameOverCoderVersion,
+  mac::{
+    Authenticity,
+    MacKey,
+  },
+  registry::{
+    Coder,
+    CoderRegistry,
+  },
+  ShareLayout,
   UnseenSetID,
 };
 use crate::{
-  coder::{
-    GameOverCoderV01,
-    Version00Coding,
-  },
+  coder::LatestCoder,
   game_over::GameOver,
 };
 
+/// The key of the token in the fragment of a share URL.
+const FRAGMENT_KEY: &str = "g";
+
 // -------------------------------------------------------------------------------------------------
 // Coder
 // -------------------------------------------------------------------------------------------------
 
 pub trait CoderVersion
 {
-  fn version() -> GameOverCoderVersion;
+  fn version() -> &'static str;
 }
 
 pub trait CoderChecksum
@@ -46,6 +57,14 @@ pub trait EncodeGameOver<T>
 pub trait DecodeGameOver<T>
 {
   type Error;
+
+  /// True if the data stores a checksum of the generated elements, see
+  /// `CoderError::BadElementChecksum`.
+  const ELEMENT_CHECKSUM: bool = true;
+
+  /// True if the data stores a checksum of the commits, see `CoderError::BadCommitChecksum`.
+  const COMMIT_CHECKSUM: bool = false;
+
   fn decode(
     data: String,
     unseen_set_id: UnseenSetID,
@@ -59,7 +78,7 @@ pub trait DecodeGameOver<T>
 
 /// Access to the encoded `data`.
 #[derive(Debug)]
-pub struct EncodedGameOver(SealedEncodedGameOver);
+pub struct EncodedGameOver(SealedEncodedGameOver, Authenticity);
 
 impl EncodedGameOver
 {
@@ -68,6 +87,18 @@ impl EncodedGameOver
   {
     &self.0.unseen_set_id
   }
+
+  /// Whether the MAC of the code was verified. Always `Authenticity::Unverified` unless the code
+  /// was verified with a `MacKey`.
+  pub fn authenticity(&self) -> Authenticity
+  {
+    self.1
+  }
+
+  pub(super) fn unverified(s: SealedEncodedGameOver) -> EncodedGameOver
+  {
+    EncodedGameOver(s, Authenticity::Unverified)
+  }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -81,7 +112,7 @@ fn default_unseen_set_id() -> UnseenSetID
 
 /// The container of data and meta data for an `Encoded`. To access the underlying data it can be
 /// cast into an `Encoded` with `SealedEncoded::try_into()`.
-#[derive(Builder, Clone, Debug, Serialize, Deserialize)]
+#[derive(Builder, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct SealedEncodedGameOver
 {
   version: String,
@@ -90,6 +121,16 @@ pub struct SealedEncodedGameOver
 
   #[serde(default = "default_unseen_set_id")]
   unseen_set_id: UnseenSetID,
+
+  // The ID of the `SealedChallenge` the game was played as a result of.
+  #[builder(default)]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  challenge: Option<u64>,
+
+  // HMAC-SHA256 of the other fields, as hex, if the code was signed with a `MacKey`.
+  #[builder(default)]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  mac: Option<String>,
 }
 
 impl SealedEncodedGameOver
@@ -101,14 +142,179 @@ impl SealedEncodedGameOver
     E: CoderVersion + CoderChecksum + EncodeGameOver<T>,
   {
     E::encode(&game_over).map(|data| SealedEncodedGameOver {
-      version: E::version().into(),
+      version: E::version().to_string(),
       checksum: E::checksum(data.as_bytes()),
       data,
       unseen_set_id: game_over.unseen_set_id().clone(),
+      challenge: None,
+      mac: None,
+    })
+  }
+
+  /// Encodes `game_over` with `coder`.
+  pub fn with_coder<T>(
+    coder: &dyn Coder<T>,
+    game_over: &GameOver<T>,
+  ) -> Result<SealedEncodedGameOver, Box<dyn Error>>
+  {
+    coder.encode(game_over).map(|data| SealedEncodedGameOver {
+      version: coder.version().to_string(),
+      checksum: coder.checksum(data.as_bytes()),
+      data,
+      unseen_set_id: game_over.unseen_set_id().clone(),
+      challenge: None,
+      mac: None,
+    })
+  }
+
+  /// Re-encodes the code with `LatestCoder`. See `CoderRegistry::migrate`.
+  pub fn migrate<T>(self, unseen: Vec<T>) -> Result<SealedEncodedGameOver, Box<dyn Error>>
+  where
+    T: Clone + PartialEq + AsRef<[u8]>,
+  {
+    CoderRegistry::default().migrate(self, unseen, <LatestCoder as CoderVersion>::version())
+  }
+
+  /// Signs the code with `key`. Must be done last since changing any other field invalidates the
+  /// MAC.
+  pub fn with_mac(mut self, key: &MacKey) -> SealedEncodedGameOver
+  {
+    self.mac = Some(key.sign(&self.mac_fields().each_ref().map(Vec::as_slice)));
+    self
+  }
+
+  /// The MAC of the code, if it was signed.
+  pub fn mac(&self) -> Option<&str>
+  {
+    self.mac.as_deref()
+  }
+
+  /// The fields covered by the MAC.
+  fn mac_fields(&self) -> [Vec<u8>; 5]
+  {
+    [
+      self.version.as_bytes().to_vec(),
+      self.checksum.to_le_bytes().to_vec(),
+      self.data.as_bytes().to_vec(),
+      self.unseen_set_id.unique_number().to_le_bytes().to_vec(),
+      self
+        .challenge
+        .map_or(Vec::new(), |x| x.to_le_bytes().to_vec()),
+    ]
+  }
+
+  /// References the `SealedChallenge` with ID `challenge` as the one the game was played for.
+  pub fn with_challenge(mut self, challenge: u64) -> SealedEncodedGameOver
+  {
+    self.challenge = Some(challenge);
+    self
+  }
+
+  /// The version of the coder that encoded the data.
+  pub fn version(&self) -> &str
+  {
+    &self.version
+  }
+
+  /// The encoded data.
+  pub fn data(&self) -> &str
+  {
+    &self.data
+  }
+
+  /// The checksum of the encoded data.
+  pub fn checksum(&self) -> u64
+  {
+    self.checksum
+  }
+
+  /// The `UnseenSetID` of the game.
+  pub fn unseen_set_id(&self) -> &UnseenSetID
+  {
+    &self.unseen_set_id
+  }
+
+  /// The ID of the `SealedChallenge` the game was played for, if any.
+  pub fn challenge(&self) -> Option<u64>
+  {
+    self.challenge
+  }
+
+  /// The code as a single URL-safe token, for the fragment or a path segment of a share URL.
+  pub fn to_token(&self) -> Result<String, Box<dyn Error>>
+  {
+    Ok(base64::encode_config(
+      serde_urlencoded::to_string(self)?,
+      base64::URL_SAFE_NO_PAD,
+    ))
+  }
+
+  /// Parses a code written with `to_token`.
+  pub fn from_token(token: &str) -> Result<SealedEncodedGameOver, Box<dyn Error>>
+  {
+    let query = base64::decode_config(token, base64::URL_SAFE_NO_PAD)?;
+    Ok(serde_urlencoded::from_bytes(&query)?)
+  }
+
+  /// The share URL of the code with `base` as the URL of the page that opens it.
+  pub fn share_url(&self, base: &str, layout: ShareLayout) -> Result<String, Box<dyn Error>>
+  {
+    Ok(match layout {
+      ShareLayout::Query => format!("{}?{}", base, serde_urlencoded::to_string(self)?),
+      ShareLayout::Fragment => format!("{}#{}={}", base, FRAGMENT_KEY, self.to_token()?),
+      ShareLayout::Path => format!("{}/{}", base.trim_end_matches('/'), self.to_token()?),
     })
   }
 }
 
+impl FromStr for SealedEncodedGameOver
+{
+  type Err = Box<dyn Error>;
+
+  /// Parses a share URL of any `ShareLayout`, or the part of one that holds the code: a search
+  /// query with or without the leading '?', a fragment with or without the leading '#', or a
+  /// token.
+  fn from_str(s: &str) -> Result<Self, Self::Err>
+  {
+    let s = s.trim();
+    match Url::parse(s) {
+      Ok(url) => {
+        if let Some(token) = url.fragment().and_then(fragment_token) {
+          SealedEncodedGameOver::from_token(&token)
+        } else if let Some(query) = url.query() {
+          Ok(serde_urlencoded::from_str(query)?)
+        } else {
+          url
+            .path_segments()
+            .and_then(|mut segments| segments.rfind(|x| !x.is_empty()))
+            .and_then(|segment| SealedEncodedGameOver::from_token(segment).ok())
+            .ok_or_else(|| SealedEncodedError::MissingCode.into())
+        }
+      }
+      Err(_) => {
+        if let Some(token) = fragment_token(s.strip_prefix('#').unwrap_or(s)) {
+          SealedEncodedGameOver::from_token(&token)
+        } else if s.contains('=') {
+          Ok(serde_urlencoded::from_str(
+            s.strip_prefix('?').unwrap_or(s),
+          )?)
+        } else {
+          SealedEncodedGameOver::from_token(s)
+        }
+      }
+    }
+  }
+}
+
+/// The token of a fragment written by `ShareLayout::Fragment`, if it has one.
+fn fragment_token(fragment: &str) -> Option<String>
+{
+  serde_urlencoded::from_str::<Vec<(String, String)>>(fragment)
+    .ok()?
+    .into_iter()
+    .find_map(|(key, value)| (key == FRAGMENT_KEY).then_some(value))
+}
+
 // -------------------------------------------------------------------------------------------------
 // Encode and decode
 // -------------------------------------------------------------------------------------------------
@@ -117,19 +323,31 @@ impl TryFrom<SealedEncodedGameOver> for EncodedGameOver
 {
   type Error = Box<dyn Error>;
 
+  /// Verifies `s` with the coders of `CoderRegistry::default()`.
   fn try_from(s: SealedEncodedGameOver) -> Result<EncodedGameOver, Self::Error>
   {
-    match GameOverCoderVersion::try_from(&s.version)? {
-      GameOverCoderVersion::Version00Coding => Ok(
-        ok_checksum::<Version00Coding>(s.checksum, s.data.as_bytes())
-          .map(|_| EncodedGameOver(s))?,
-      ),
+    // The checksum does not depend on the type of the elements.
+    CoderRegistry::<String>::default().verify(s)
+  }
+}
 
-      GameOverCoderVersion::GameOverCoderV01 => Ok(
-        ok_checksum::<GameOverCoderV01>(s.checksum, s.data.as_bytes())
-          .map(|_| EncodedGameOver(s))?,
-      ),
-    }
+impl TryFrom<(SealedEncodedGameOver, &MacKey)> for EncodedGameOver
+{
+  type Error = Box<dyn Error>;
+
+  /// Same as `EncodedGameOver::try_from(s)` but also verifies the MAC of `s` with `key`. Throws if
+  /// the MAC does not match. A code without a MAC is accepted as `Authenticity::Unverified`.
+  fn try_from((s, key): (SealedEncodedGameOver, &MacKey)) -> Result<EncodedGameOver, Self::Error>
+  {
+    let authenticity = match &s.mac {
+      None => Authenticity::Unverified,
+      Some(mac) if key.verify(&s.mac_fields().each_ref().map(Vec::as_slice), mac) => {
+        Authenticity::Verified
+      }
+      Some(_) => return Err(Box::new(SealedEncodedError::InvalidMac)),
+    };
+    let EncodedGameOver(s, _) = EncodedGameOver::try_from(s)?;
+    Ok(EncodedGameOver(s, authenticity))
   }
 }
 
@@ -139,35 +357,13 @@ where
 {
   type Error = Box<dyn std::error::Error>;
 
+  /// Decodes `s` with the coders of `CoderRegistry::default()`.
   fn try_from((s, unseen): (SealedEncodedGameOver, Vec<T>)) -> Result<GameOver<T>, Self::Error>
   {
-    match GameOverCoderVersion::try_from(&s.version)? {
-      GameOverCoderVersion::Version00Coding => decode::<Version00Coding, _>(s, unseen),
-      GameOverCoderVersion::GameOverCoderV01 => decode::<GameOverCoderV01, _>(s, unseen),
-    }
+    CoderRegistry::default().decode(s, unseen)
   }
 }
 
-//
-// Helpers
-//
-
-fn ok_checksum<C: CoderChecksum>(checksum: u64, data: &[u8]) -> Result<(), SealedEncodedError>
-{
-  (checksum == C::checksum(data))
-    .then(|| ())
-    .ok_or(SealedEncodedError::InvalidChecksum)
-}
-
-fn decode<C, T>(s: SealedEncodedGameOver, unseen: Vec<T>) -> Result<GameOver<T>, Box<dyn Error>>
-where
-  C: CoderChecksum + DecodeGameOver<T, Error = Box<dyn Error>>,
-  T: Clone + PartialEq + AsRef<[u8]>,
-{
-  ok_checksum::<C>(s.checksum, s.data.as_bytes())?;
-  C::decode(s.data, s.unseen_set_id, unseen)
-}
-
 // -------------------------------------------------------------------------------------------------
 // SealedEncodedError
 // -------------------------------------------------------------------------------------------------
@@ -176,6 +372,9 @@ where
 pub enum SealedEncodedError
 {
   InvalidChecksum,
+  InvalidMac,
+  MissingCode,
+  MissingQuery,
 }
 
 impl Display for SealedEncodedError
@@ -186,8 +385,37 @@ impl Display for SealedEncodedError
 
     match self {
       InvalidChecksum => writeln!(f, "the data is currupted"),
+      InvalidMac => writeln!(f, "the code was not signed with the key"),
+      MissingCode => writeln!(f, "url has no share code"),
+      MissingQuery => writeln!(f, "url has no search query"),
     }
   }
 }
 
 impl std::error::Error for SealedEncodedError {}
+
+/// Errors thrown by every coder when the decoded game does not match a checksum in its data.
+#[derive(Debug, PartialEq)]
+pub enum CoderError
+{
+  /// The elements were not generated from the same unseen elements as when the game was encoded.
+  BadElementChecksum,
+
+  /// The replayed commits differ from the encoded ones.
+  BadCommitChecksum,
+}
+
+impl Display for CoderError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use CoderError::*;
+
+    match self {
+      BadElementChecksum => writeln!(f, "element checksum does not match the accumelated"),
+      BadCommitChecksum => writeln!(f, "commit checksum does not match the replayed commits"),
+    }
+  }
+}
+
+impl std::error::Error for CoderError {}
@@ -1,15 +1,38 @@
 #[cfg(test)]
 mod game_over_coder_v01;
 
+#[cfg(test)]
+mod game_over_coder_v02;
+
+#[cfg(test)]
+mod game_over_coder_v03;
+
+#[cfg(test)]
+mod game_over_coder_v04;
+
 #[cfg(test)]
 mod version00coding;
 
-use std::collections::BTreeSet;
+use std::{
+  collections::BTreeSet,
+  iter,
+};
 
 use super::*;
 use crate::{
-  coder::UnseenSetID,
-  game::SeenThreshold,
+  coder::{
+    GameOverCoderV01,
+    GameOverCoderV02,
+    GameOverCoderV03,
+    GameOverCoderV04,
+    UnseenSetID,
+    Version00Coding,
+  },
+  game::{
+    Confidence,
+    GameMode,
+    SeenThreshold,
+  },
   game_over::GameOver,
   rng::Konadare192PxPlusPlus,
 };
@@ -19,6 +42,16 @@ fn generate_game_over(
   unseen_set_id: Option<UnseenSetID>,
   seen_threshold: Option<SeenThreshold>,
 ) -> (GameOver<String>, Vec<String>)
+{
+  generate_game_over_with_mode(s, unseen_set_id, seen_threshold, GameMode::Classic)
+}
+
+fn generate_game_over_with_mode(
+  s: u64,
+  unseen_set_id: Option<UnseenSetID>,
+  seen_threshold: Option<SeenThreshold>,
+  mode: GameMode,
+) -> (GameOver<String>, Vec<String>)
 {
   let mut rng = Konadare192PxPlusPlus::from_seed(s);
 
@@ -44,7 +77,7 @@ fn generate_game_over(
   let unseen: Vec<String> = unseen.into_iter().collect();
 
   (
-    GameOver::new(
+    GameOver::with_mode(
       seed,
       unseen_set_id.unwrap_or(UnseenSetID::Unspecified),
       unseen.clone(),
@@ -53,8 +86,135 @@ fn generate_game_over(
           .try_into()
           .unwrap()
       }),
-      incorrect,
-    ),
+      mode,
+      incorrect.to_vec(),
+    )
+    .unwrap(),
     unseen,
   )
 }
+
+/// Replaces `from` with `to` in the encoded data and reseals it with a valid checksum of `C`.
+fn reseal<C: CoderChecksum>(
+  sealed: &SealedEncodedGameOver,
+  from: &str,
+  to: &str,
+) -> SealedEncodedGameOver
+{
+  let data = String::from_utf8(base64::decode(&sealed.data).unwrap()).unwrap();
+  assert!(data.contains(from));
+  let mut sealed = sealed.clone();
+  sealed.data = base64::encode(data.replacen(from, to, 1));
+  sealed.checksum = C::checksum(sealed.data.as_bytes());
+  sealed
+}
+
+#[test]
+fn parse_sealed_from_url_and_query()
+{
+  let (game_over, _) = generate_game_over(3373163411394129591, None, None);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+  let query = serde_urlencoded::to_string(&sealed).unwrap();
+
+  for s in [
+    format!("https://felixnaredi.github.io/deja-vu/game-over/?{}", query),
+    format!("?{}", query),
+    query.clone(),
+  ] {
+    let parsed: SealedEncodedGameOver = s.parse().unwrap();
+    assert_eq!(parsed.version(), "goc-v02");
+    assert_eq!(parsed.checksum(), sealed.checksum());
+    assert_eq!(parsed.data, sealed.data);
+  }
+
+  assert!("https://felixnaredi.github.io/deja-vu/game-over/"
+    .parse::<SealedEncodedGameOver>()
+    .is_err());
+}
+
+#[test]
+fn parse_sealed_from_fragment_and_path()
+{
+  let base = "https://felixnaredi.github.io/deja-vu/game-over/";
+  let (game_over, unseen) = generate_game_over(6151925434917452417, None, None);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over)
+    .unwrap()
+    .with_challenge(3);
+  let token = sealed.to_token().unwrap();
+  assert!(token
+    .chars()
+    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+
+  let fragment = sealed.share_url(base, ShareLayout::Fragment).unwrap();
+  assert_eq!(fragment, format!("{}#g={}", base, token));
+  let path = sealed.share_url(base, ShareLayout::Path).unwrap();
+  assert_eq!(path, format!("{}{}", base, token));
+  let query = sealed.share_url(base, ShareLayout::Query).unwrap();
+
+  for s in [
+    fragment,
+    path,
+    query,
+    format!("{}?utm_source=chat#g={}", base, token),
+    format!("{}#lang=fr&g={}", base, token),
+    format!("#g={}", token),
+    format!("g={}", token),
+    token.clone(),
+  ] {
+    let parsed: SealedEncodedGameOver = s.parse().unwrap();
+    assert_eq!(parsed.version(), "goc-v03");
+    assert_eq!(parsed.checksum(), sealed.checksum());
+    assert_eq!(parsed.challenge(), Some(3));
+    let decoded: GameOver<String> = (parsed, unseen.clone()).try_into().unwrap();
+    assert!(decoded == game_over);
+  }
+
+  assert!(format!("{}#g=", base)
+    .parse::<SealedEncodedGameOver>()
+    .is_err());
+}
+
+#[test]
+fn keyed_codes_verify_only_with_the_key()
+{
+  let key: MacKey = "5eb63bbbe01eeed093cb22bb8f5acdc3".parse().unwrap();
+  let (game_over, unseen) = generate_game_over(5281770312358919461, None, None);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over)
+    .unwrap()
+    .with_challenge(17)
+    .with_mac(&key);
+
+  // The MAC survives the share URL.
+  let sealed: SealedEncodedGameOver = serde_urlencoded::to_string(&sealed)
+    .unwrap()
+    .parse()
+    .unwrap();
+  let encoded = EncodedGameOver::try_from((sealed.clone(), &key)).unwrap();
+  assert_eq!(encoded.authenticity(), Authenticity::Verified);
+
+  // Without the key the code still decodes, but unverified.
+  let encoded = EncodedGameOver::try_from(sealed.clone()).unwrap();
+  assert_eq!(encoded.authenticity(), Authenticity::Unverified);
+  let decoded: GameOver<String> = (sealed.clone(), unseen).try_into().unwrap();
+  assert!(decoded == game_over);
+
+  // Another key, or a change to any field, is rejected.
+  assert!(EncodedGameOver::try_from((sealed.clone(), &MacKey::new("other"))).is_err());
+  let mut changed = sealed.clone();
+  changed.challenge = Some(18);
+  assert!(EncodedGameOver::try_from((changed, &key)).is_err());
+  let mut changed = sealed.clone();
+  changed.unseen_set_id = UnseenSetID::DictionaryFr01;
+  assert!(EncodedGameOver::try_from((changed, &key)).is_err());
+}
+
+#[test]
+fn unkeyed_codes_are_unverified()
+{
+  let key = MacKey::new("secret");
+  let (game_over, _) = generate_game_over(8446185301277212907, None, None);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over).unwrap();
+  assert_eq!(sealed.mac(), None);
+  let encoded = EncodedGameOver::try_from((sealed, &key)).unwrap();
+  assert_eq!(encoded.authenticity(), Authenticity::Unverified);
+}
@@ -0,0 +1,163 @@
+use super::*;
+use crate::game::{
+  Game,
+  GameError,
+};
+
+#[test]
+fn encode_decode_same_as_id()
+{
+  for mode in [
+    GameMode::Classic,
+    GameMode::Adaptive,
+    GameMode::Window(6),
+    GameMode::NBack(2),
+    GameMode::ForcedChoice,
+  ] {
+    let (game_over, unseen) =
+      generate_game_over_with_mode(7006247016392867359, None, None, mode.clone());
+    let encoded = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+    let decoded: GameOver<String> = (encoded, unseen).try_into().unwrap();
+    assert_eq!(decoded, game_over);
+    assert_eq!(decoded.mode(), &mode);
+  }
+}
+
+#[test]
+fn decode_with_wrong_mode_fails()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(1460379410446302497, None, None, GameMode::Adaptive);
+  let encoded = serde_urlencoded::to_string(
+    SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap(),
+  )
+  .unwrap();
+
+  // Swap the mode in the encoded data and reseal it with a valid checksum.
+  let mut sealed: SealedEncodedGameOver = serde_urlencoded::from_str(&encoded).unwrap();
+  let data = String::from_utf8(base64::decode(&sealed.data).unwrap())
+    .unwrap()
+    .replace("\"Adaptive\"", "\"Classic\"");
+  sealed.data = base64::encode(data);
+  sealed.checksum = GameOverCoderV02::checksum(sealed.data.as_bytes());
+
+  assert!(TryInto::<GameOver<String>>::try_into((sealed, unseen)).is_err());
+}
+
+#[test]
+fn v01_refuses_to_encode_adaptive_game()
+{
+  let (game_over, _) =
+    generate_game_over_with_mode(11635927451185311493, None, None, GameMode::Adaptive);
+  assert!(SealedEncodedGameOver::new::<GameOverCoderV01, _>(&game_over).is_err());
+}
+
+#[test]
+fn encode_decode_window_keeps_k()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(6590036468612401203, None, None, GameMode::Window(8));
+  let encoded = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+  let decoded: GameOver<String> = (encoded.clone(), unseen.clone()).try_into().unwrap();
+  assert_eq!(decoded, game_over);
+  assert_eq!(decoded.mode(), &GameMode::Window(8));
+
+  // Changing K must be detected.
+  let mut sealed = encoded;
+  let data = String::from_utf8(base64::decode(&sealed.data).unwrap())
+    .unwrap()
+    .replace("{\"Window\":8}", "{\"Window\":9}");
+  sealed.data = base64::encode(data);
+  sealed.checksum = GameOverCoderV02::checksum(sealed.data.as_bytes());
+  assert!(TryInto::<GameOver<String>>::try_into((sealed, unseen)).is_err());
+}
+
+#[test]
+fn encode_decode_keeps_confidences()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(17297130386094512733, None, None, GameMode::Classic);
+  let confidences: Vec<Option<Confidence>> = (0..game_over.commit_count())
+    .map(|i| Confidence::try_from((i % 4) as u8).ok())
+    .collect();
+  let game_over = game_over.with_confidences(confidences.clone());
+
+  let encoded = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+  let decoded: GameOver<String> = (encoded, unseen).try_into().unwrap();
+  assert_eq!(decoded.confidences(), confidences.as_slice());
+  assert!(iter::zip(decoded.iter(), confidences.iter()).all(|(x, y)| &x.confidence() == y));
+}
+
+#[test]
+fn encode_leaves_out_missing_confidences()
+{
+  let (game_over, _) =
+    generate_game_over_with_mode(3542301990412566389, None, None, GameMode::Classic);
+  let encoded = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+  let data = String::from_utf8(base64::decode(&encoded.data).unwrap()).unwrap();
+  assert!(!data.contains("confidences"));
+}
+
+#[test]
+fn decode_with_wrong_amount_of_confidences_fails()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(8817553036616236623, None, None, GameMode::Classic);
+  let game_over = game_over.with_confidences(vec![Some(Confidence::Sure)]);
+  let mut sealed = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+
+  let data = String::from_utf8(base64::decode(&sealed.data).unwrap()).unwrap();
+  let data = data.replacen("\"confidences\":\"1", "\"confidences\":\"", 1);
+  sealed.data = base64::encode(data);
+  sealed.checksum = GameOverCoderV02::checksum(sealed.data.as_bytes());
+  assert!(TryInto::<GameOver<String>>::try_into((sealed, unseen)).is_err());
+}
+
+#[test]
+fn decode_forced_choice_without_incorrect_commits_fails()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(7006247016392867359, None, None, GameMode::ForcedChoice);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+  let data: serde_json::Value =
+    serde_json::from_slice(&base64::decode(&sealed.data).unwrap()).unwrap();
+
+  // A game that is never lost runs out of pairs to choose from.
+  let altered = reseal::<GameOverCoderV02>(
+    &sealed,
+    &format!("\"incorrect_commits\":{}", data["incorrect_commits"]),
+    "\"incorrect_commits\":[null,null,null]",
+  );
+  let error = TryInto::<GameOver<String>>::try_into((altered, unseen)).unwrap_err();
+  assert!(error.downcast_ref::<GameError>().is_some());
+}
+
+#[test]
+fn only_games_with_three_lives_are_coded()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(7006247016392867359, None, None, GameMode::Classic);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+  let data: serde_json::Value =
+    serde_json::from_slice(&base64::decode(&sealed.data).unwrap()).unwrap();
+  let incorrect_commits = format!("\"incorrect_commits\":{}", data["incorrect_commits"]);
+
+  for forged in ["[]", "[null]", "[1,2,3,4]"] {
+    let altered = reseal::<GameOverCoderV02>(
+      &sealed,
+      &incorrect_commits,
+      &format!("\"incorrect_commits\":{}", forged),
+    );
+    assert!(TryInto::<GameOver<String>>::try_into((altered, unseen.clone())).is_err());
+  }
+
+  let mut game =
+    Game::new(5, 0.5.try_into().unwrap(), UnseenSetID::Unspecified, unseen).with_lives(5);
+  game.next().unwrap();
+  game.commit_with(false, None).unwrap();
+  let game_over = GameOver::from(game);
+  assert!(SealedEncodedGameOver::new::<GameOverCoderV01, _>(&game_over).is_err());
+  assert!(SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).is_err());
+  assert!(SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over).is_err());
+  assert!(SealedEncodedGameOver::new::<GameOverCoderV04, _>(&game_over).is_ok());
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+fn with_reaction_times(game_over: GameOver<String>) -> GameOver<String>
+{
+  let reaction_times = (0..game_over.commit_count())
+    .map(|i| (i % 5 != 0).then_some(400 + 37 * i as u32))
+    .collect();
+  game_over.with_reaction_times(reaction_times)
+}
+
+#[test]
+fn encode_decode_same_as_id()
+{
+  for mode in [
+    GameMode::Classic,
+    GameMode::Adaptive,
+    GameMode::Window(6),
+    GameMode::NBack(2),
+    GameMode::ForcedChoice,
+  ] {
+    let (game_over, unseen) =
+      generate_game_over_with_mode(4130577284719045613, None, None, mode.clone());
+    let game_over = with_reaction_times(game_over);
+    let encoded = SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over).unwrap();
+    let decoded: GameOver<String> = (encoded, unseen).try_into().unwrap();
+    assert_eq!(decoded, game_over);
+    assert_eq!(decoded.mode(), &mode);
+    assert_eq!(decoded.reaction_times(), game_over.reaction_times());
+    assert_eq!(decoded.commit_checksum(), game_over.commit_checksum());
+  }
+}
+
+#[test]
+fn commit_checksum_depends_on_reaction_times()
+{
+  let (game_over, _) =
+    generate_game_over_with_mode(9013744021596126887, None, None, GameMode::Classic);
+  let timed = with_reaction_times(game_over.clone());
+  assert_ne!(timed.commit_checksum(), game_over.commit_checksum());
+  assert_eq!(
+    timed.with_reaction_times(Vec::new()).commit_checksum(),
+    game_over.commit_checksum()
+  );
+}
+
+#[test]
+fn decode_with_altered_history_fails()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(2245183355306941211, None, None, GameMode::Classic);
+  let game_over = with_reaction_times(game_over);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over).unwrap();
+
+  // A changed reaction time.
+  let t = game_over.reaction_times()[1].unwrap();
+  let altered = reseal::<GameOverCoderV03>(&sealed, &format!(",{},", t), &format!(",{},", t + 1));
+  assert!(TryInto::<GameOver<String>>::try_into((altered, unseen.clone())).is_err());
+
+  // A changed commit checksum.
+  let checksum = game_over.commit_checksum();
+  let altered = reseal::<GameOverCoderV03>(
+    &sealed,
+    &format!("\"commit_checksum\":{}", checksum),
+    &format!("\"commit_checksum\":{}", checksum ^ 1),
+  );
+  assert!(TryInto::<GameOver<String>>::try_into((altered, unseen.clone())).is_err());
+
+  // The untouched code still decodes.
+  assert!(TryInto::<GameOver<String>>::try_into((sealed, unseen)).is_ok());
+}
+
+#[test]
+fn encode_leaves_out_missing_reaction_times()
+{
+  let (game_over, _) =
+    generate_game_over_with_mode(3542301990412566389, None, None, GameMode::Classic);
+  let encoded = SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over).unwrap();
+  let data = String::from_utf8(base64::decode(&encoded.data).unwrap()).unwrap();
+  assert!(!data.contains("reaction_times"));
+  assert!(data.contains("commit_checksum"));
+}
@@ -0,0 +1,158 @@
+use super::*;
+use crate::{
+  coder::GameOverCoderV04Error,
+  game::{
+    Game,
+    MAX_LIVES,
+  },
+};
+
+fn with_extra_fields(game_over: GameOver<String>) -> GameOver<String>
+{
+  let n = game_over.commit_count();
+  let confidences = (0..n)
+    .map(|i| Confidence::try_from((i % 4) as u8).ok())
+    .collect();
+  let reaction_times = (0..n)
+    .map(|i| (i % 3 != 0).then_some(350 + 29 * i as u32))
+    .collect();
+  game_over
+    .with_confidences(confidences)
+    .with_reaction_times(reaction_times)
+}
+
+/// The decoded data of `sealed` as JSON.
+fn data(sealed: &SealedEncodedGameOver) -> serde_json::Value
+{
+  serde_json::from_slice(&base64::decode(&sealed.data).unwrap()).unwrap()
+}
+
+#[test]
+fn encode_decode_same_as_id()
+{
+  for mode in [
+    GameMode::Classic,
+    GameMode::Adaptive,
+    GameMode::Window(6),
+    GameMode::NBack(2),
+    GameMode::ForcedChoice,
+  ] {
+    let (game_over, unseen) =
+      generate_game_over_with_mode(1185764307331826393, None, None, mode.clone());
+    let game_over = with_extra_fields(game_over);
+    let encoded = SealedEncodedGameOver::new::<GameOverCoderV04, _>(&game_over).unwrap();
+    let decoded: GameOver<String> = (encoded, unseen).try_into().unwrap();
+    assert!(decoded.iter().eq(game_over.iter()));
+    assert_eq!(decoded.mode(), &mode);
+    assert_eq!(decoded.guesses(), game_over.guesses());
+    assert_eq!(decoded.confidences(), game_over.confidences());
+    assert_eq!(decoded.reaction_times(), game_over.reaction_times());
+    assert_eq!(decoded.commit_checksum(), game_over.commit_checksum());
+  }
+}
+
+#[test]
+fn guesses_are_one_bit_per_commit()
+{
+  let (game_over, _) =
+    generate_game_over_with_mode(7731925170441361109, None, None, GameMode::Classic);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV04, _>(&game_over).unwrap();
+  let data = data(&sealed);
+  let guesses = base64::decode(data["guesses"].as_str().unwrap()).unwrap();
+  assert_eq!(data["commit_count"], game_over.commit_count());
+  assert_eq!(guesses.len(), game_over.commit_count().div_ceil(8));
+  for (i, guess) in game_over.guesses().iter().enumerate() {
+    assert_eq!(guesses[i / 8] & (1 << (i % 8)) != 0, *guess);
+  }
+}
+
+#[test]
+fn encode_decode_unfinished_game()
+{
+  let unseen: Vec<String> = (0..100).map(|i| format!("{:02}", i)).collect();
+  let mut game = Game::new(
+    3,
+    0.5.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    unseen.clone(),
+  )
+  .with_lives(5);
+  for i in 0..11 {
+    game.next().unwrap();
+    let seen = game.is_current_seen().unwrap();
+    game.commit_with(seen ^ (i % 4 == 0), None).unwrap();
+  }
+  let game_over = GameOver::from(game);
+  assert_eq!(game_over.lives(), 2);
+
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV04, _>(&game_over).unwrap();
+  let decoded: GameOver<String> = (sealed, unseen).try_into().unwrap();
+  assert_eq!(decoded.commit_count(), 11);
+  assert_eq!(decoded.initial_lives(), 5);
+  assert_eq!(decoded.lives(), 2);
+  assert!(decoded.iter().eq(game_over.iter()));
+}
+
+#[test]
+fn decode_with_altered_guesses_fails()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(5607288142180449337, None, None, GameMode::Adaptive);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV04, _>(&game_over).unwrap();
+  let guesses = data(&sealed)["guesses"].as_str().unwrap().to_string();
+
+  // A flipped guess.
+  let mut bytes = base64::decode(&guesses).unwrap();
+  bytes[0] ^= 1;
+  let altered = reseal::<GameOverCoderV04>(&sealed, &guesses, &base64::encode(&bytes));
+  assert!(TryInto::<GameOver<String>>::try_into((altered, unseen.clone())).is_err());
+
+  // Guesses missing for the last commits.
+  let altered = reseal::<GameOverCoderV04>(&sealed, &guesses, "AA==");
+  assert!(TryInto::<GameOver<String>>::try_into((altered, unseen.clone())).is_err());
+
+  // The untouched code still decodes.
+  assert!(TryInto::<GameOver<String>>::try_into((sealed, unseen)).is_ok());
+}
+
+#[test]
+fn decode_with_out_of_range_lives_fails()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(2290117624403514181, None, None, GameMode::Classic);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV04, _>(&game_over).unwrap();
+
+  for lives in [0, MAX_LIVES + 1, usize::MAX] {
+    let altered =
+      reseal::<GameOverCoderV04>(&sealed, "\"lives\":3,", &format!("\"lives\":{},", lives));
+    let error = TryInto::<GameOver<String>>::try_into((altered, unseen.clone())).unwrap_err();
+    assert!(matches!(
+      error.downcast_ref::<GameOverCoderV04Error>(),
+      Some(GameOverCoderV04Error::BadLives(x)) if *x == lives
+    ));
+  }
+}
+
+#[test]
+fn decode_with_more_commits_than_unseen_elements_fails()
+{
+  let (game_over, unseen) =
+    generate_game_over_with_mode(7731925170441361109, None, None, GameMode::Classic);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV04, _>(&game_over).unwrap();
+  let data = data(&sealed);
+  let commit_count = format!("\"commit_count\":{},", data["commit_count"]);
+  let guesses = data["guesses"].as_str().unwrap().to_string();
+
+  for n in [unseen.len() + 1, usize::MAX] {
+    // A guess of seen for each claimed commit, up to one more than there are unseen elements.
+    let bytes = vec![0xff; n.min(unseen.len() + 1).div_ceil(8)];
+    let altered =
+      reseal::<GameOverCoderV04>(&sealed, &commit_count, &format!("\"commit_count\":{},", n));
+    let altered = reseal::<GameOverCoderV04>(&altered, &guesses, &base64::encode(&bytes));
+    let error = TryInto::<GameOver<String>>::try_into((altered, unseen.clone())).unwrap_err();
+    assert!(matches!(
+      error.downcast_ref::<GameOverCoderV04Error>(),
+      Some(GameOverCoderV04Error::BadGuesses)
+    ));
+  }
+}
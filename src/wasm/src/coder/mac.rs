@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+  error::Error,
+  fmt::Display,
+  str::FromStr,
+};
+
+use hmac::{
+  Hmac,
+  Mac,
+};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+// -------------------------------------------------------------------------------------------------
+// MacKey
+// -------------------------------------------------------------------------------------------------
+
+/// A secret key supplied by the host, used to authenticate share codes with HMAC-SHA256. Only
+/// holders of the key can produce codes that verify.
+#[derive(Clone)]
+pub struct MacKey(Vec<u8>);
+
+impl MacKey
+{
+  pub fn new(key: impl Into<Vec<u8>>) -> MacKey
+  {
+    MacKey(key.into())
+  }
+
+  /// The MAC of `fields` as a hex string. Each field is prefixed with its length so that the
+  /// boundaries between them are part of the message.
+  pub(crate) fn sign(&self, fields: &[&[u8]]) -> String
+  {
+    to_hex(&self.hmac(fields).finalize().into_bytes())
+  }
+
+  /// True if `mac` is the MAC of `fields`. The comparison is done in constant time.
+  pub(crate) fn verify(&self, fields: &[&[u8]], mac: &str) -> bool
+  {
+    from_hex(mac).is_some_and(|mac| self.hmac(fields).verify_slice(&mac).is_ok())
+  }
+
+  fn hmac(&self, fields: &[&[u8]]) -> HmacSha256
+  {
+    // HMAC accepts keys of any length.
+    let mut hmac = HmacSha256::new_from_slice(&self.0).unwrap();
+    for field in fields {
+      hmac.update(&(field.len() as u64).to_le_bytes());
+      hmac.update(field);
+    }
+    hmac
+  }
+}
+
+impl std::fmt::Debug for MacKey
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    write!(f, "MacKey(..)")
+  }
+}
+
+impl FromStr for MacKey
+{
+  type Err = MacKeyError;
+
+  /// Parses a key written as hex.
+  fn from_str(s: &str) -> Result<Self, Self::Err>
+  {
+    match from_hex(s.trim()) {
+      Some(key) if !key.is_empty() => Ok(MacKey(key)),
+      _ => Err(MacKeyError::InvalidHex),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MacKeyError
+{
+  InvalidHex,
+}
+
+impl Display for MacKeyError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use MacKeyError::*;
+
+    match self {
+      InvalidHex => write!(f, "the key is not a non-empty hex string"),
+    }
+  }
+}
+
+impl Error for MacKeyError {}
+
+// -------------------------------------------------------------------------------------------------
+// Authenticity
+// -------------------------------------------------------------------------------------------------
+
+/// Whether an `EncodedGameOver` was produced by a holder of the `MacKey` it was verified with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Authenticity
+{
+  /// The MAC of the code matched the key.
+  Verified,
+
+  /// The code has no MAC, or no key was given to verify it with. It still decodes but anyone could
+  /// have produced it.
+  Unverified,
+}
+
+//
+// Helpers
+//
+
+fn to_hex(bytes: &[u8]) -> String
+{
+  bytes.iter().map(|x| format!("{:02x}", x)).collect()
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>>
+{
+  if !s.len().is_multiple_of(2) || !s.is_ascii() {
+    return None;
+  }
+  (0..s.len())
+    .step_by(2)
+    .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+    .collect()
+}
@@ -0,0 +1,35 @@
+use super::*;
+
+#[test]
+fn hmac_sha256_test_vector()
+{
+  // RFC 4231, test case 2, with the fields as a single message without length prefixes.
+  let mut hmac = HmacSha256::new_from_slice(b"Jefe").unwrap();
+  hmac.update(b"what do ya want for nothing?");
+  assert_eq!(
+    to_hex(&hmac.finalize().into_bytes()),
+    "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+  );
+}
+
+#[test]
+fn verify_only_accepts_the_same_key_and_fields()
+{
+  let key: MacKey = "0123456789abcdef".parse().unwrap();
+  let mac = key.sign(&[b"goc-v03", b"data"]);
+  assert_eq!(mac.len(), 64);
+  assert!(key.verify(&[b"goc-v03", b"data"], &mac));
+  assert!(!key.verify(&[b"goc-v03", b"date"], &mac));
+  assert!(!key.verify(&[b"goc-v03d", b"ata"], &mac));
+  assert!(!MacKey::new("other").verify(&[b"goc-v03", b"data"], &mac));
+  assert!(!key.verify(&[b"goc-v03", b"data"], "not hex"));
+}
+
+#[test]
+fn parse_key_from_hex()
+{
+  assert!("".parse::<MacKey>().is_err());
+  assert!("abc".parse::<MacKey>().is_err());
+  assert!("zz".parse::<MacKey>().is_err());
+  assert_eq!("00ff".parse::<MacKey>().unwrap().0, vec![0, 255]);
+}
@@ -0,0 +1,290 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+  collections::BTreeMap,
+  error::Error,
+  fmt::Display,
+  marker::PhantomData,
+};
+
+use crate::{
+  coder::{
+    encoded_game_over::{
+      CoderChecksum,
+      CoderVersion,
+      DecodeGameOver,
+      EncodeGameOver,
+      SealedEncodedError,
+    },
+    Challenge,
+    EncodedGameOver,
+    GameOverCoderV01,
+    GameOverCoderV02,
+    GameOverCoderV03,
+    GameOverCoderV04,
+    SealedEncodedGameOver,
+    UnseenSetID,
+    Version00Coding,
+  },
+  game_over::GameOver,
+};
+
+// -------------------------------------------------------------------------------------------------
+// Coder
+// -------------------------------------------------------------------------------------------------
+
+/// A coder of games with elements of type `T` that can be stored in a `CoderRegistry`.
+pub trait Coder<T>
+{
+  /// The version string that identifies the coder in a `SealedEncodedGameOver`.
+  fn version(&self) -> &str;
+
+  fn checksum(&self, data: &[u8]) -> u64;
+
+  fn encode(&self, game_over: &GameOver<T>) -> Result<String, Box<dyn Error>>;
+
+  fn decode(
+    &self,
+    data: String,
+    unseen_set_id: UnseenSetID,
+    unseen: Vec<T>,
+  ) -> Result<GameOver<T>, Box<dyn Error>>;
+
+  /// True if the data stores a checksum of the generated elements. Decoding then fails with
+  /// `CoderError::BadElementChecksum` if the unseen elements differ from the encoded game.
+  fn has_element_checksum(&self) -> bool;
+
+  /// True if the data stores a checksum of the commits. Decoding then fails with
+  /// `CoderError::BadCommitChecksum` if the replayed commits differ from the encoded game.
+  fn has_commit_checksum(&self) -> bool;
+}
+
+/// Adapts a coder implemented with the associated functions of `CoderVersion`, `CoderChecksum`,
+/// `EncodeGameOver<T>` and `DecodeGameOver<T>` to `Coder<T>`.
+struct CoderType<C>(PhantomData<C>);
+
+impl<C, T> Coder<T> for CoderType<C>
+where
+  C: CoderVersion + CoderChecksum + EncodeGameOver<T> + DecodeGameOver<T>,
+  <C as EncodeGameOver<T>>::Error: Into<Box<dyn Error>>,
+  <C as DecodeGameOver<T>>::Error: Into<Box<dyn Error>>,
+{
+  fn version(&self) -> &str
+  {
+    <C as CoderVersion>::version()
+  }
+
+  fn checksum(&self, data: &[u8]) -> u64
+  {
+    <C as CoderChecksum>::checksum(data)
+  }
+
+  fn encode(&self, game_over: &GameOver<T>) -> Result<String, Box<dyn Error>>
+  {
+    <C as EncodeGameOver<T>>::encode(game_over).map_err(Into::into)
+  }
+
+  fn decode(
+    &self,
+    data: String,
+    unseen_set_id: UnseenSetID,
+    unseen: Vec<T>,
+  ) -> Result<GameOver<T>, Box<dyn Error>>
+  {
+    <C as DecodeGameOver<T>>::decode(data, unseen_set_id, unseen).map_err(Into::into)
+  }
+
+  fn has_element_checksum(&self) -> bool
+  {
+    <C as DecodeGameOver<T>>::ELEMENT_CHECKSUM
+  }
+
+  fn has_commit_checksum(&self) -> bool
+  {
+    <C as DecodeGameOver<T>>::COMMIT_CHECKSUM
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// CoderRegistry
+// -------------------------------------------------------------------------------------------------
+
+/// The coders that a `SealedEncodedGameOver` can be sealed and opened with, keyed by their version.
+///
+/// `CoderRegistry::default()` holds every coder of this crate. A new format only has to be
+/// registered with `with_coder`.
+pub struct CoderRegistry<T>
+{
+  coders: BTreeMap<String, Box<dyn Coder<T>>>,
+}
+
+impl<T> CoderRegistry<T>
+{
+  /// A registry without any coders.
+  pub fn new() -> CoderRegistry<T>
+  {
+    CoderRegistry {
+      coders: BTreeMap::new(),
+    }
+  }
+
+  /// Registers `coder` under its version. Replaces any coder already registered with the same
+  /// version.
+  pub fn with_coder(mut self, coder: impl Coder<T> + 'static) -> CoderRegistry<T>
+  {
+    self
+      .coders
+      .insert(coder.version().to_string(), Box::new(coder));
+    self
+  }
+
+  /// Registers the coder `C` implemented with the associated functions of `CoderVersion`,
+  /// `CoderChecksum`, `EncodeGameOver<T>` and `DecodeGameOver<T>`.
+  pub fn with_coder_type<C>(self) -> CoderRegistry<T>
+  where
+    C: CoderVersion + CoderChecksum + EncodeGameOver<T> + DecodeGameOver<T> + 'static,
+    <C as EncodeGameOver<T>>::Error: Into<Box<dyn Error>>,
+    <C as DecodeGameOver<T>>::Error: Into<Box<dyn Error>>,
+  {
+    self.with_coder(CoderType::<C>(PhantomData))
+  }
+
+  /// The coder registered with `version`.
+  pub fn coder(&self, version: &str) -> Result<&dyn Coder<T>, CoderRegistryError>
+  {
+    self
+      .coders
+      .get(version)
+      .map(Box::as_ref)
+      .ok_or_else(|| CoderRegistryError::UnrecognisedVersion(version.to_string()))
+  }
+
+  /// The versions of the registered coders in lexicographical order.
+  pub fn versions(&self) -> impl Iterator<Item = &str>
+  {
+    self.coders.keys().map(String::as_str)
+  }
+
+  /// Encodes `game_over` with the coder registered with `version`.
+  pub fn seal(
+    &self,
+    version: &str,
+    game_over: &GameOver<T>,
+  ) -> Result<SealedEncodedGameOver, Box<dyn Error>>
+  {
+    SealedEncodedGameOver::with_coder(self.coder(version)?, game_over)
+  }
+
+  /// Checks that the version of `sealed` is registered and that its checksum is correct.
+  pub fn verify(&self, sealed: SealedEncodedGameOver) -> Result<EncodedGameOver, Box<dyn Error>>
+  {
+    ok_checksum(self.coder(sealed.version())?, &sealed)?;
+    Ok(EncodedGameOver::unverified(sealed))
+  }
+
+  /// Verifies `sealed` and decodes it with the elements in `unseen`.
+  pub fn decode(
+    &self,
+    sealed: SealedEncodedGameOver,
+    unseen: Vec<T>,
+  ) -> Result<GameOver<T>, Box<dyn Error>>
+  {
+    let coder = self.coder(sealed.version())?;
+    ok_checksum(coder, &sealed)?;
+    coder.decode(
+      sealed.data().to_string(),
+      sealed.unseen_set_id().clone(),
+      unseen,
+    )
+  }
+}
+
+impl<T> CoderRegistry<T>
+where
+  T: Clone + PartialEq + AsRef<[u8]>,
+{
+  /// Decodes `sealed` and seals the game again with the coder registered with `version`. Throws
+  /// unless the new code replays exactly like `sealed`.
+  ///
+  /// The reference to a challenge is kept. A MAC is not, since only the holder of the key can sign
+  /// the new code.
+  pub fn migrate(
+    &self,
+    sealed: SealedEncodedGameOver,
+    unseen: Vec<T>,
+    version: &str,
+  ) -> Result<SealedEncodedGameOver, Box<dyn Error>>
+  {
+    let challenge = sealed.challenge();
+    let game_over = self.decode(sealed, unseen.clone())?;
+
+    let mut migrated = self.seal(version, &game_over)?;
+    if let Some(challenge) = challenge {
+      migrated = migrated.with_challenge(challenge);
+    }
+
+    let replayed = self.decode(migrated.clone(), unseen)?;
+    if Challenge::from(&replayed) != Challenge::from(&game_over)
+      || !replayed.iter().eq(game_over.iter())
+    {
+      return Err(Box::new(CoderRegistryError::ReplayMismatch));
+    }
+    Ok(migrated)
+  }
+}
+
+impl<T> Default for CoderRegistry<T>
+where
+  T: Clone + PartialEq + AsRef<[u8]>,
+{
+  fn default() -> Self
+  {
+    CoderRegistry::new()
+      .with_coder_type::<Version00Coding>()
+      .with_coder_type::<GameOverCoderV01>()
+      .with_coder_type::<GameOverCoderV02>()
+      .with_coder_type::<GameOverCoderV03>()
+      .with_coder_type::<GameOverCoderV04>()
+  }
+}
+
+//
+// Helpers
+//
+
+fn ok_checksum<T>(
+  coder: &dyn Coder<T>,
+  sealed: &SealedEncodedGameOver,
+) -> Result<(), SealedEncodedError>
+{
+  (coder.checksum(sealed.data().as_bytes()) == sealed.checksum())
+    .then_some(())
+    .ok_or(SealedEncodedError::InvalidChecksum)
+}
+
+// -------------------------------------------------------------------------------------------------
+// CoderRegistryError
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub enum CoderRegistryError
+{
+  UnrecognisedVersion(String),
+  ReplayMismatch,
+}
+
+impl Display for CoderRegistryError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use CoderRegistryError::*;
+
+    match self {
+      UnrecognisedVersion(s) => write!(f, "version '{}' is unrecognised", s),
+      ReplayMismatch => write!(f, "the migrated code does not replay like the original"),
+    }
+  }
+}
+
+impl Error for CoderRegistryError {}
@@ -0,0 +1,230 @@
+use super::*;
+use crate::{
+  coder::{
+    CoderError,
+    Version00CodingError,
+  },
+  game::Game,
+  rng::{
+    IndexedPermutation,
+    KSINK,
+  },
+};
+
+/// An experimental format that prefixes the data of `GameOverCoderV03`, the way a downstream
+/// crate could register its own.
+struct ExperimentalCoder;
+
+impl Coder<String> for ExperimentalCoder
+{
+  fn version(&self) -> &str
+  {
+    "exp-v01"
+  }
+
+  fn checksum(&self, data: &[u8]) -> u64
+  {
+    KSINK::hash(1, data)
+  }
+
+  fn encode(&self, game_over: &GameOver<String>) -> Result<String, Box<dyn Error>>
+  {
+    <GameOverCoderV03 as EncodeGameOver<String>>::encode(game_over).map(|data| format!("x{}", data))
+  }
+
+  fn decode(
+    &self,
+    data: String,
+    unseen_set_id: UnseenSetID,
+    unseen: Vec<String>,
+  ) -> Result<GameOver<String>, Box<dyn Error>>
+  {
+    <GameOverCoderV03 as DecodeGameOver<String>>::decode(
+      data[1..].to_string(),
+      unseen_set_id,
+      unseen,
+    )
+  }
+
+  fn has_element_checksum(&self) -> bool
+  {
+    true
+  }
+
+  fn has_commit_checksum(&self) -> bool
+  {
+    true
+  }
+}
+
+fn unseen() -> Vec<String>
+{
+  (0..200).map(|i| format!("{:03}", i)).collect()
+}
+
+fn game_over() -> GameOver<String>
+{
+  play(Game::new(
+    4114,
+    0.5.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    unseen(),
+  ))
+}
+
+fn play(mut game: Game<String>) -> GameOver<String>
+{
+  let mut i = 0;
+  while !game.finished() {
+    game.next().unwrap();
+    let seen = game.is_current_seen().unwrap();
+    i += 1;
+    game.set_reaction_time(400 + 10 * i);
+    game.commit_with(seen ^ (i % 9 == 0), None).unwrap();
+  }
+  GameOver::from(game)
+}
+
+#[test]
+fn default_registry_has_every_builtin_coder()
+{
+  let registry = CoderRegistry::<String>::default();
+  assert_eq!(
+    registry.versions().collect::<Vec<_>>(),
+    vec!["00", "goc-v01", "goc-v02", "goc-v03", "goc-v04"]
+  );
+  assert!(registry.coder("goc-v99").is_err());
+}
+
+#[test]
+fn coders_report_their_checksums()
+{
+  let registry = CoderRegistry::<String>::default();
+  let other_words: Vec<String> = unseen().into_iter().rev().collect();
+
+  for version in registry.versions() {
+    let coder = registry.coder(version).unwrap();
+    assert_eq!(coder.has_element_checksum(), version != "00");
+    assert_eq!(
+      coder.has_commit_checksum(),
+      ["goc-v03", "goc-v04"].contains(&version)
+    );
+
+    if coder.has_element_checksum() {
+      let sealed = registry.seal(version, &game_over()).unwrap();
+      let e = registry.decode(sealed, other_words.clone()).unwrap_err();
+      assert_eq!(e.downcast_ref(), Some(&CoderError::BadElementChecksum));
+    }
+  }
+}
+
+#[test]
+fn registered_coder_seals_and_decodes()
+{
+  let game_over = game_over();
+
+  let sealed = CoderRegistry::default()
+    .seal("goc-v03", &game_over)
+    .unwrap();
+  assert_eq!(sealed.version(), "goc-v03");
+  assert!(CoderRegistry::default().decode(sealed, unseen()).unwrap() == game_over);
+
+  let registry = CoderRegistry::default().with_coder(ExperimentalCoder);
+  let sealed = registry.seal("exp-v01", &game_over).unwrap();
+  assert_eq!(sealed.version(), "exp-v01");
+  assert!(sealed.data().starts_with('x'));
+  assert!(registry.decode(sealed.clone(), unseen()).unwrap() == game_over);
+
+  // The default registry does not know the experimental version.
+  assert!(GameOver::try_from((sealed.clone(), unseen())).is_err());
+  assert!(EncodedGameOver::try_from(sealed).is_err());
+}
+
+#[test]
+fn migrate_legacy_codes_to_the_latest_version()
+{
+  let registry = CoderRegistry::default();
+
+  // `Version00Coding` only stores games of `DictionaryFr01` with a seen threshold of 0.4.
+  let fr01 = play(Game::new(
+    2718,
+    0.4.try_into().unwrap(),
+    UnseenSetID::DictionaryFr01,
+    unseen(),
+  ));
+  let legacy = registry.seal("00", &fr01).unwrap();
+  let migrated = registry
+    .migrate(legacy.clone(), unseen(), "goc-v03")
+    .unwrap();
+  assert_eq!(migrated.version(), "goc-v03");
+  assert_eq!(migrated.unseen_set_id(), &UnseenSetID::DictionaryFr01);
+  assert!(
+    registry.decode(migrated, unseen()).unwrap() == registry.decode(legacy, unseen()).unwrap()
+  );
+
+  let legacy = registry
+    .seal("goc-v01", &game_over())
+    .unwrap()
+    .with_challenge(99);
+  let migrated = registry
+    .migrate(legacy.clone(), unseen(), "goc-v03")
+    .unwrap();
+  assert_eq!(migrated.challenge(), Some(99));
+  assert!(
+    registry.decode(migrated, unseen()).unwrap() == registry.decode(legacy, unseen()).unwrap()
+  );
+}
+
+#[test]
+fn legacy_coder_refuses_games_it_can_not_restore()
+{
+  let registry = CoderRegistry::default();
+  let game = || {
+    Game::new(
+      2718,
+      0.4.try_into().unwrap(),
+      UnseenSetID::DictionaryFr01,
+      unseen(),
+    )
+  };
+
+  let mut unfinished = game();
+  unfinished.next().unwrap();
+  let seen = unfinished.is_current_seen().unwrap();
+  unfinished.commit_with(!seen, None).unwrap();
+
+  for game_over in [GameOver::from(unfinished), play(game().with_lives(5))] {
+    let e = registry.seal("00", &game_over).unwrap_err();
+    assert_eq!(
+      e.downcast_ref::<Version00CodingError>(),
+      Some(&Version00CodingError::UnsupportedGame)
+    );
+  }
+  assert!(registry.seal("00", &play(game())).is_ok());
+
+  let data =
+    base64::encode(r#"{"unseen_id":"DictionaryFr01","seed":2718,"incorrect_commits":[3]}"#);
+  let e = registry
+    .coder("00")
+    .unwrap()
+    .decode(data, UnseenSetID::DictionaryFr01, unseen())
+    .unwrap_err();
+  assert_eq!(
+    e.downcast_ref::<Version00CodingError>(),
+    Some(&Version00CodingError::BadIncorrectCommits)
+  );
+}
+
+#[test]
+fn migration_that_loses_data_is_rejected()
+{
+  let registry = CoderRegistry::default();
+  let sealed = registry.seal("goc-v03", &game_over()).unwrap();
+
+  // `GameOverCoderV02` does not store reaction times.
+  let e = registry.migrate(sealed, unseen(), "goc-v02").unwrap_err();
+  assert_eq!(
+    e.downcast_ref::<CoderRegistryError>(),
+    Some(&CoderRegistryError::ReplayMismatch)
+  );
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test;
+
+use schemars::{
+  schema::RootSchema,
+  schema_for,
+};
+
+use crate::coder::{
+  coder::{
+    GameOverCoderV01Data,
+    GameOverCoderV02Data,
+    GameOverCoderV03Data,
+    GameOverCoderV04Data,
+  },
+  SealedEncodedGameOver,
+  Version00Coding,
+};
+
+/// The JSON Schemas of the wire format of share codes, keyed by the name of the type.
+///
+/// A share code is a `SealedEncodedGameOver`. Its `data` is the base64 of a JSON payload with the
+/// schema of its `version`: `Version00Coding` for "00" and `GameOverCoderV0<n>Data` for "goc-v0<n>".
+pub fn schemas() -> Vec<(&'static str, RootSchema)>
+{
+  vec![
+    ("SealedEncodedGameOver", schema_for!(SealedEncodedGameOver)),
+    ("GameOverCoderV01Data", schema_for!(GameOverCoderV01Data)),
+    ("GameOverCoderV02Data", schema_for!(GameOverCoderV02Data)),
+    ("GameOverCoderV03Data", schema_for!(GameOverCoderV03Data)),
+    ("GameOverCoderV04Data", schema_for!(GameOverCoderV04Data)),
+    ("Version00Coding", schema_for!(Version00Coding)),
+  ]
+}
@@ -0,0 +1,271 @@
+use std::{
+  env,
+  fs,
+  path::PathBuf,
+};
+
+use serde::{
+  Deserialize,
+  Serialize,
+};
+
+use super::*;
+use crate::{
+  coder::{
+    CoderRegistry,
+    UnseenSetID,
+  },
+  game::{
+    Confidence,
+    GameMode,
+    IncorrectCommits,
+  },
+  game_over::GameOver,
+};
+
+// Run the tests with `UPDATE_SPEC=1` to rewrite the spec after an intended change of the format.
+const UPDATE_SPEC: &str = "UPDATE_SPEC";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct GoldenVectors
+{
+  unseen: Vec<String>,
+  vectors: Vec<GoldenVector>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct GoldenVector
+{
+  version: String,
+  seed: u64,
+  seen_threshold: f64,
+  unseen_set_id: UnseenSetID,
+  mode: GameMode,
+  incorrect_commits: IncorrectCommits,
+  /// The confidence of each commit from 1 to 3, if the game has confidences.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  confidences: Option<Vec<Option<u8>>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  reaction_times: Option<Vec<Option<u32>>>,
+  elements: Vec<String>,
+  query: String,
+}
+
+fn spec_path(name: &str) -> PathBuf
+{
+  PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("spec")
+    .join(name)
+}
+
+/// Fails unless the checked-in file at `name` is `expected`, or rewrites it if `UPDATE_SPEC` is
+/// set.
+fn assert_spec(name: &str, expected: &str)
+{
+  let path = spec_path(name);
+  if env::var_os(UPDATE_SPEC).is_some() {
+    fs::write(&path, expected).unwrap();
+  }
+  let actual = fs::read_to_string(&path).unwrap_or_default();
+  assert!(
+    actual == expected,
+    "{} is out of date, run the tests with {}=1 if the change of the format is intended",
+    path.display(),
+    UPDATE_SPEC
+  );
+}
+
+fn golden_vectors() -> GoldenVectors
+{
+  let unseen: Vec<String> = (0..128).map(|i| format!("w{:03}", i)).collect();
+  let registry = CoderRegistry::default();
+
+  // Games in other modes and with confidences and reaction times exercise the optional fields
+  // of the coders that store them.
+  let vectors = [
+    (
+      "00",
+      1666334722536,
+      0.4,
+      UnseenSetID::DictionaryFr01,
+      GameMode::Classic,
+      [4, 9, 17],
+      false,
+    ),
+    (
+      "goc-v01",
+      9007199254740991,
+      0.55,
+      UnseenSetID::Unspecified,
+      GameMode::Classic,
+      [0, 12, 13],
+      false,
+    ),
+    (
+      "goc-v02",
+      42,
+      0.3,
+      UnseenSetID::Top999WiktionaryFr,
+      GameMode::Classic,
+      [7, 8, 30],
+      false,
+    ),
+    (
+      "goc-v02",
+      3141592653589793238,
+      0.4,
+      UnseenSetID::Unspecified,
+      GameMode::Window(8),
+      [3, 15, 22],
+      true,
+    ),
+    (
+      "goc-v03",
+      7359453237177161485,
+      0.7,
+      UnseenSetID::Unspecified,
+      GameMode::Classic,
+      [2, 21, 25],
+      false,
+    ),
+    (
+      "goc-v03",
+      2718281828459045235,
+      0.5,
+      UnseenSetID::Unspecified,
+      GameMode::Adaptive,
+      [6, 11, 27],
+      true,
+    ),
+    (
+      "goc-v04",
+      16775286842649692529,
+      0.45,
+      UnseenSetID::Unspecified,
+      GameMode::Classic,
+      [5, 6, 19],
+      false,
+    ),
+    (
+      "goc-v04",
+      1618033988749894848,
+      0.5,
+      UnseenSetID::Unspecified,
+      GameMode::ForcedChoice,
+      [4, 10, 16],
+      true,
+    ),
+    (
+      "goc-v04",
+      1414213562373095048,
+      0.4,
+      UnseenSetID::Unspecified,
+      GameMode::NBack(2),
+      [8, 9, 20],
+      true,
+    ),
+  ]
+  .into_iter()
+  .map(
+    |(version, seed, ratio, unseen_set_id, mode, incorrect, annotated)| {
+      let incorrect_commits: IncorrectCommits = incorrect.map(Some).to_vec();
+      let mut game_over = GameOver::with_mode(
+        seed,
+        unseen_set_id.clone(),
+        unseen.clone(),
+        f64::try_into(ratio).unwrap(),
+        mode.clone(),
+        incorrect_commits.clone(),
+      )
+      .unwrap();
+      let (mut confidences, mut reaction_times) = (None, None);
+      if annotated {
+        let n = game_over.commit_count();
+        let c: Vec<Option<u8>> = (0..n)
+          .map(|i| Some(i as u8 % 4).filter(|&x| x > 0))
+          .collect();
+        let t: Vec<Option<u32>> = (0..n)
+          .map(|i| (i % 5 != 4).then_some(450 + 37 * (i as u32 % 11)))
+          .collect();
+        game_over = game_over.with_confidences(
+          c.iter()
+            .map(|x| x.map(|x| Confidence::try_from(x).unwrap()))
+            .collect(),
+        );
+        // Goc-v02 does not store reaction times.
+        if version != "goc-v02" {
+          game_over = game_over.with_reaction_times(t.clone());
+          reaction_times = Some(t);
+        }
+        confidences = Some(c);
+      }
+      let sealed = registry.seal(version, &game_over).unwrap();
+      GoldenVector {
+        version: version.to_string(),
+        seed,
+        seen_threshold: ratio,
+        unseen_set_id,
+        mode,
+        incorrect_commits,
+        confidences,
+        reaction_times,
+        elements: game_over.iter().map(|x| x.element().clone()).collect(),
+        query: serde_urlencoded::to_string(&sealed).unwrap(),
+      }
+    },
+  )
+  .collect();
+
+  GoldenVectors { unseen, vectors }
+}
+
+#[test]
+fn schemas_match_the_spec()
+{
+  for (name, schema) in schemas() {
+    assert_spec(
+      &format!("schema/{}.json", name),
+      &(serde_json::to_string_pretty(&schema).unwrap() + "\n"),
+    );
+  }
+}
+
+#[test]
+fn golden_vectors_match_the_spec()
+{
+  let golden = golden_vectors();
+  assert_spec(
+    "golden-vectors.json",
+    &(serde_json::to_string_pretty(&golden).unwrap() + "\n"),
+  );
+
+  // The checked-in codes also decode to the checked-in games.
+  let spec: GoldenVectors =
+    serde_json::from_str(&fs::read_to_string(spec_path("golden-vectors.json")).unwrap()).unwrap();
+  for vector in spec.vectors {
+    let sealed: SealedEncodedGameOver = vector.query.parse().unwrap();
+    assert_eq!(sealed.version(), vector.version);
+    let game_over: GameOver<String> = (sealed, spec.unseen.clone()).try_into().unwrap();
+    assert_eq!(game_over.seed(), vector.seed);
+    assert_eq!(game_over.mode(), &vector.mode);
+    assert_eq!(game_over.incorrect_commits(), vector.incorrect_commits);
+    if let Some(confidences) = vector.confidences {
+      let decoded: Vec<Option<u8>> = game_over
+        .confidences()
+        .iter()
+        .map(|x| x.map(u8::from))
+        .collect();
+      assert_eq!(decoded, confidences);
+    }
+    if let Some(reaction_times) = vector.reaction_times {
+      assert_eq!(game_over.reaction_times(), reaction_times);
+    }
+    assert_eq!(
+      game_over
+        .iter()
+        .map(|x| x.element().clone())
+        .collect::<Vec<_>>(),
+      vector.elements
+    );
+  }
+}
@@ -0,0 +1,57 @@
+use std::{
+  error::Error,
+  fmt::Display,
+  str::FromStr,
+};
+
+/// Where in a share URL a `SealedEncodedGameOver` is placed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShareLayout
+{
+  /// The fields of the code as search parameters, `?version=...&checksum=...&data=...`.
+  #[default]
+  Query,
+
+  /// The code as a single token in the fragment, `#g=...`.
+  Fragment,
+
+  /// The code as a single token in the last path segment, `/...`.
+  Path,
+}
+
+impl FromStr for ShareLayout
+{
+  type Err = ShareLayoutError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err>
+  {
+    use ShareLayout::*;
+
+    match s {
+      "query" => Ok(Query),
+      "fragment" => Ok(Fragment),
+      "path" => Ok(Path),
+      _ => Err(ShareLayoutError::UnrecognisedLayout(s.into())),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ShareLayoutError
+{
+  UnrecognisedLayout(String),
+}
+
+impl Display for ShareLayoutError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use ShareLayoutError::*;
+
+    match self {
+      UnrecognisedLayout(s) => write!(f, "share layout '{}' is unrecognised", s),
+    }
+  }
+}
+
+impl Error for ShareLayoutError {}
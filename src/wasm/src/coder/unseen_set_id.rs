@@ -1,10 +1,17 @@
+use std::{
+  error::Error,
+  fmt::Display,
+  str::FromStr,
+};
+
+use schemars::JsonSchema;
 use serde::{
   Deserialize,
   Serialize,
 };
 
 /// ID of the set that elements are picked from.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
 pub enum UnseenSetID
 {
   /// Can be used for tests.
@@ -19,6 +26,14 @@ pub enum UnseenSetID
 
 impl UnseenSetID
 {
+  /// Every `UnseenSetID`.
+  pub fn all() -> [UnseenSetID; 3]
+  {
+    use UnseenSetID::*;
+
+    [Unspecified, DictionaryFr01, Top999WiktionaryFr]
+  }
+
   /// Unique number for each value of `&self`.
   pub fn unique_number(&self) -> u64
   {
@@ -30,4 +45,53 @@ impl UnseenSetID
       Top999WiktionaryFr => 4682054772874934823,
     }
   }
+
+  /// The directory in `public/dictionary` that holds the words of the set.
+  pub fn directory(&self) -> Option<&'static str>
+  {
+    use UnseenSetID::*;
+
+    match self {
+      Unspecified => None,
+      DictionaryFr01 => Some("fr01"),
+      Top999WiktionaryFr => Some("top999-wiktionary-fr"),
+    }
+  }
 }
+
+impl FromStr for UnseenSetID
+{
+  type Err = UnseenSetIDError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err>
+  {
+    use UnseenSetID::*;
+
+    match s {
+      "Unspecified" => Ok(Unspecified),
+      "DictionaryFr01" => Ok(DictionaryFr01),
+      "Top999WiktionaryFr" => Ok(Top999WiktionaryFr),
+      _ => Err(UnseenSetIDError::UnrecognisedID(s.into())),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UnseenSetIDError
+{
+  UnrecognisedID(String),
+}
+
+impl Display for UnseenSetIDError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use UnseenSetIDError::*;
+
+    match self {
+      UnrecognisedID(s) => write!(f, "unseen set id '{}' is unrecognised", s),
+    }
+  }
+}
+
+impl Error for UnseenSetIDError {}
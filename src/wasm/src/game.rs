@@ -1,13 +1,21 @@
+mod confidence;
 mod game;
 mod game_error;
+mod game_mode;
 mod unseen;
 
+pub use confidence::Confidence;
 pub use game::{
   Game,
   IncorrectCommits,
   SeenThreshold,
   SeenThresholdError,
   INITIAL_LIVES_AMOUNT,
+  MAX_LIVES,
 };
 pub use game_error::GameError;
+pub use game_mode::{
+  GameMode,
+  Staircase,
+};
 pub use unseen::Unseen;
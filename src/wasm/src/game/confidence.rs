@@ -0,0 +1,59 @@
+use std::{
+  error::Error,
+  fmt::Display,
+};
+
+use serde::{
+  Deserialize,
+  Serialize,
+};
+
+/// How confident the player is in a commit.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence
+{
+  Sure = 1,
+  Think = 2,
+  Guess = 3,
+}
+
+#[derive(Debug)]
+pub enum ConfidenceError
+{
+  ValueOutOfRange,
+}
+
+impl Display for ConfidenceError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    writeln!(f, "{:?} - confidence must be in range [1, 3]", self)
+  }
+}
+
+impl Error for ConfidenceError {}
+
+impl TryFrom<u8> for Confidence
+{
+  type Error = ConfidenceError;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error>
+  {
+    use Confidence::*;
+
+    match value {
+      1 => Ok(Sure),
+      2 => Ok(Think),
+      3 => Ok(Guess),
+      _ => Err(ConfidenceError::ValueOutOfRange),
+    }
+  }
+}
+
+impl From<Confidence> for u8
+{
+  fn from(confidence: Confidence) -> Self
+  {
+    confidence as u8
+  }
+}
@@ -2,10 +2,12 @@
 mod test;
 
 use std::{
+  collections::VecDeque,
   error::Error,
   fmt::Display,
 };
 
+use schemars::JsonSchema;
 use serde::{
   Deserialize,
   Serialize,
@@ -14,7 +16,10 @@ use serde::{
 use crate::{
   coder::UnseenSetID,
   game::{
+    Confidence,
     GameError,
+    GameMode,
+    Staircase,
     Unseen,
   },
   rng::{
@@ -27,13 +32,27 @@ use crate::{
 const THRESHOLD_MAX: u32 = 1_000_000_000;
 
 pub const DEFAULT_ELEMENT_CHECKSUM: u64 = 2636128771936786712;
+pub const DEFAULT_COMMIT_CHECKSUM: u64 = 9521371360290735453;
 pub const INITIAL_LIVES_AMOUNT: usize = 3;
+/// The most lives a game can start with.
+pub const MAX_LIVES: usize = 99;
 
-pub type IncorrectCommits = [Option<usize>; INITIAL_LIVES_AMOUNT];
+/// The index of each incorrect commit, with one slot for each life the game started with.
+pub type IncorrectCommits = Vec<Option<usize>>;
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+/// The chance that the next element is picked from the seen elements, in billionths.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
 pub struct SeenThreshold(u32);
 
+impl SeenThreshold
+{
+  /// The threshold as a ratio in range [0.0, 1.0].
+  pub fn ratio(&self) -> f64
+  {
+    self.0 as f64 / THRESHOLD_MAX as f64
+  }
+}
+
 #[derive(Debug)]
 pub enum SeenThresholdError
 {
@@ -76,17 +95,33 @@ pub struct Game<T>
   // TODO:
   //   It could be possible to have seen just store indices and not the elements themself.
   seen: Vec<T>,
+  // The index of the commit each element in `seen` was last generated for.
+  seen_at: Vec<usize>,
   current: Option<T>,
+  // The second element of the pair when the mode is `GameMode::ForcedChoice`.
+  alternative: Option<T>,
   previuos: Option<T>,
+  // The last generated elements. Only kept when the mode is `GameMode::NBack`.
+  recent: VecDeque<T>,
   incorrect_commits: IncorrectCommits,
+  // The guess of each commit, true for seen.
+  guesses: Vec<bool>,
+  confidences: Vec<Option<Confidence>>,
+  // The time in milliseconds it took to answer each commit, if it was measured.
+  reaction_times: Vec<Option<u32>>,
+  // The reaction time set for the current element, added with the next commit.
+  reaction_time: Option<u32>,
   rng: Konadare192PxPlusPlus,
   seen_threshold: u32,
   count: usize,
+  mode: GameMode,
+  staircase: Staircase,
 
   // TODO:
   //   The unit tests does not offer full coverage for the fields below.
   unseen_set_id: UnseenSetID,
   element_checksum: u64,
+  commit_checksum: u64,
 }
 
 impl<T> Game<T>
@@ -98,39 +133,84 @@ impl<T> Game<T>
     unseen_set_id: UnseenSetID,
     unseen: Vec<T>,
   ) -> Game<T>
+  {
+    Game::with_mode(
+      seed,
+      seen_threshold,
+      unseen_set_id,
+      unseen,
+      GameMode::Classic,
+    )
+  }
+
+  /// Create a new game played with the rules of `mode`.
+  pub fn with_mode(
+    seed: u64,
+    seen_threshold: SeenThreshold,
+    unseen_set_id: UnseenSetID,
+    unseen: Vec<T>,
+    mode: GameMode,
+  ) -> Game<T>
   {
     Game {
       seed,
       unseen: Unseen::new(unseen),
       seen: Vec::new(),
+      seen_at: Vec::new(),
       current: None,
+      alternative: None,
       previuos: None,
-      incorrect_commits: [None; 3],
+      recent: VecDeque::new(),
+      incorrect_commits: vec![None; INITIAL_LIVES_AMOUNT],
+      guesses: Vec::new(),
+      confidences: Vec::new(),
+      reaction_times: Vec::new(),
+      reaction_time: None,
       rng: Konadare192PxPlusPlus::from_seed(seed),
       seen_threshold: seen_threshold.0,
       count: 0,
+      mode,
+      staircase: Staircase::default(),
       element_checksum: KSINK::permute_index(
         unseen_set_id.unique_number(),
         DEFAULT_ELEMENT_CHECKSUM,
       ),
+      commit_checksum: KSINK::permute_index(seed, DEFAULT_COMMIT_CHECKSUM),
       unseen_set_id,
     }
   }
 
+  /// Sets the amount of lives the game starts with, at least one and at most `MAX_LIVES`. Must be
+  /// called before the first commit.
+  pub fn with_lives(mut self, lives: usize) -> Game<T>
+  {
+    self.incorrect_commits = vec![None; lives.clamp(1, MAX_LIVES)];
+    self
+  }
+
   /// Reset `self`. Given the same input it will now reproduce its output.
   pub fn reset(&mut self)
   {
     self.unseen.reset();
     self.seen = Vec::new();
+    self.seen_at = Vec::new();
     self.current = None;
+    self.alternative = None;
     self.previuos = None;
+    self.recent = VecDeque::new();
     for x in self.incorrect_commits.iter_mut() {
       x.take();
     }
+    self.guesses = Vec::new();
+    self.confidences = Vec::new();
+    self.reaction_times = Vec::new();
+    self.reaction_time = None;
     self.rng = Konadare192PxPlusPlus::from_seed(self.seed);
     self.count = 0;
+    self.staircase = Staircase::default();
     self.element_checksum =
       KSINK::permute_index(self.unseen_set_id.unique_number(), DEFAULT_ELEMENT_CHECKSUM);
+    self.commit_checksum = KSINK::permute_index(self.seed, DEFAULT_COMMIT_CHECKSUM);
   }
 
   /// Returns how many lives the game has left.
@@ -143,16 +223,69 @@ impl<T> Game<T>
       .sum()
   }
 
-  /// Returns the score, i.e the amount of correct commits.
+  /// Returns the score, i.e the amount of correct commits. The opening element of
+  /// `GameMode::ForcedChoice` is not scored.
   pub fn score(&self) -> usize
   {
-    self.count - (INITIAL_LIVES_AMOUNT - self.lives())
+    self.count - (self.initial_lives() - self.lives()) - self.opening_commits()
+  }
+
+  /// The amount of commits made that were not scored, see `Game::is_opening`.
+  fn opening_commits(&self) -> usize
+  {
+    (self.mode == GameMode::ForcedChoice && self.count > 0) as usize
+  }
+
+  /// True if the current element is the opening element of `GameMode::ForcedChoice`. It only
+  /// exists so that the first pair has a seen element, and it is always unseen. Committing it never
+  /// costs a life and is recorded as unseen whatever the guess.
+  fn is_opening(&self) -> bool
+  {
+    self.mode == GameMode::ForcedChoice && self.alternative.is_none() && self.seen.is_empty()
+  }
+
+  /// The amount of lives the game started with.
+  pub fn initial_lives(&self) -> usize
+  {
+    self.incorrect_commits.len()
   }
 
   /// Indicies of incorrect commits.
   pub fn incorrect_commits(&self) -> IncorrectCommits
   {
-    self.incorrect_commits
+    self.incorrect_commits.clone()
+  }
+
+  /// The guess of each commit, `true` if the element was claimed to be seen. In
+  /// `GameMode::ForcedChoice` it is `true` if the first element of the pair was chosen.
+  pub fn guesses(&self) -> &[bool]
+  {
+    &self.guesses
+  }
+
+  /// The confidence given for each commit.
+  pub fn confidences(&self) -> &[Option<Confidence>]
+  {
+    &self.confidences
+  }
+
+  /// The time in milliseconds it took to answer each commit, if it was measured.
+  pub fn reaction_times(&self) -> &[Option<u32>]
+  {
+    &self.reaction_times
+  }
+
+  /// Sets the time in milliseconds it took to answer the current element. It is stored with the
+  /// next commit.
+  pub fn set_reaction_time(&mut self, milliseconds: u32)
+  {
+    self.reaction_time = Some(milliseconds);
+  }
+
+  /// The amount of commits made.
+  pub fn commit_count(&self) -> usize
+  {
+    self.count
   }
 
   /// Seed used in the game.
@@ -161,10 +294,10 @@ impl<T> Game<T>
     self.seed
   }
 
-  /// True if three wrong commits has been made.
+  /// True if all lives are lost.
   pub fn finished(&self) -> bool
   {
-    self.incorrect_commits[2].is_some()
+    self.lives() == 0
   }
 
   /// Throws `GameError::GameOver` if `Game::finished` is `true`.
@@ -183,6 +316,13 @@ impl<T> Game<T>
     self.element_checksum
   }
 
+  /// The running checksum of the commits. Each commit folds in its element, the guess and the
+  /// reaction time if there is one, which makes any change to the history of the game detectable.
+  pub fn commit_checksum(&self) -> u64
+  {
+    self.commit_checksum
+  }
+
   /// The `UnseenSetID` of the `Game`.
   pub fn unseen_set_id(&self) -> &UnseenSetID
   {
@@ -193,6 +333,71 @@ impl<T> Game<T>
   {
     SeenThreshold(self.seen_threshold)
   }
+
+  /// The `GameMode` the game is played with.
+  pub fn mode(&self) -> &GameMode
+  {
+    &self.mode
+  }
+
+  /// The `Staircase` of the game. It is only updated if the mode is `GameMode::Adaptive`.
+  pub fn staircase(&self) -> &Staircase
+  {
+    &self.staircase
+  }
+
+  /// The seen threshold used for generating the next element.
+  fn current_seen_threshold(&self) -> u32
+  {
+    match self.mode {
+      GameMode::Adaptive => self.staircase.seen_threshold(self.seen_threshold),
+      _ => self.seen_threshold,
+    }
+  }
+
+  /// True if the element at index `i` in `seen` currently counts as seen.
+  fn in_window(&self, i: usize) -> bool
+  {
+    match self.mode {
+      GameMode::Window(k) => self.count - self.seen_at[i] <= k,
+      _ => true,
+    }
+  }
+
+  /// The element generated `n` commits ago.
+  fn n_back(&self, n: usize) -> Option<&T>
+  {
+    (n > 0 && self.recent.len() >= n).then(|| &self.recent[self.recent.len() - n])
+  }
+
+  /// True if `x` counts as seen given the elements generated so far.
+  fn counts_as_seen(&self, x: &T) -> bool
+  where
+    T: PartialEq,
+  {
+    match self.mode {
+      GameMode::NBack(n) => self.n_back(n) == Some(x),
+      _ => self
+        .seen
+        .iter()
+        .enumerate()
+        .any(|(i, y)| y == x && self.in_window(i)),
+    }
+  }
+
+  /// True if the current element counts as seen. Returns `None` if there is no current element.
+  pub fn is_current_seen(&self) -> Option<bool>
+  where
+    T: PartialEq,
+  {
+    self.current.as_ref().map(|x| self.counts_as_seen(x))
+  }
+
+  /// True if the next element must be generated with `Game::next_pair`.
+  pub fn expects_pair(&self) -> bool
+  {
+    self.mode == GameMode::ForcedChoice && !self.seen.is_empty()
+  }
 }
 
 impl<T> Game<T>
@@ -200,6 +405,8 @@ where
   T: Clone + PartialEq + AsRef<[u8]>,
 {
   /// Generates the next value.
+  // Not an `Iterator` since the value borrows the game and must be committed before the next.
+  #[allow(clippy::should_implement_trait)]
   pub fn next(&mut self) -> Result<&T, GameError>
   {
     self.game_over()?;
@@ -212,15 +419,71 @@ where
 
     // With that checked it is now possible to generate the next value.
 
+    if self.expects_pair() {
+      return Err(GameError::UnsupportedByMode);
+    }
+
+    match self.mode {
+      GameMode::Window(k) => return self.next_in_window(k),
+      GameMode::NBack(n) => return self.next_n_back(n),
+      _ => (),
+    }
+
     if self.seen.len() < 2
-      || self.rng.next_with_upper_bound(THRESHOLD_MAX as u32) > self.seen_threshold
+      || self.rng.next_with_upper_bound(THRESHOLD_MAX as u32) > self.current_seen_threshold()
     {
       self.next_unseen()
+    } else if self.mode == GameMode::Adaptive {
+      self.next_seen_with_lag()
     } else {
       self.next_seen()
     }
   }
 
+  /// Generates the next pair of elements when the game is played in `GameMode::ForcedChoice`.
+  /// Exactly one of the elements is seen.
+  pub fn next_pair(&mut self) -> Result<(&T, &T), GameError>
+  {
+    self.game_over()?;
+
+    if !self.expects_pair() {
+      return Err(GameError::UnsupportedByMode);
+    }
+    if self.current.is_some() {
+      return Err(GameError::NextCalledWithUncommitedResult);
+    }
+
+    // Avoid picking an element from the previous commit unless there is no other choice.
+    let mut candidates: Vec<usize> = (0..self.seen.len())
+      .filter(|&i| self.seen_at[i] + 1 != self.count)
+      .collect();
+    if candidates.is_empty() {
+      candidates = (0..self.seen.len()).collect();
+    }
+    let i = candidates[self.rng.next_with_upper_bound(candidates.len() as u32) as usize];
+    let seen = self.seen[i].clone();
+    let unseen = self
+      .unseen
+      .poll(&mut self.rng)
+      .cloned()
+      .ok_or(GameError::UnseenEmpty)?;
+
+    let (x, y) = if self.rng.next_with_upper_bound(2) == 0 {
+      (seen, unseen)
+    } else {
+      (unseen, seen)
+    };
+    self.element_checksum = KSINK::hash(self.element_checksum, x.as_ref());
+    self.element_checksum = KSINK::hash(self.element_checksum, y.as_ref());
+    self.current = Some(x);
+    self.alternative = Some(y);
+
+    Ok((
+      self.current.as_ref().unwrap(),
+      self.alternative.as_ref().unwrap(),
+    ))
+  }
+
   fn next_unseen(&mut self) -> Result<&T, GameError>
   {
     let x = self
@@ -253,6 +516,82 @@ where
     Ok(self.current.as_ref().unwrap())
   }
 
+  /// Generates a seen element that was last generated at least `Staircase::lag` commits ago. Falls
+  /// back to `next_seen` if there is no such element.
+  fn next_seen_with_lag(&mut self) -> Result<&T, GameError>
+  {
+    let lag = self.staircase.lag();
+    let candidates: Vec<usize> = (0..self.seen.len())
+      .filter(|&i| {
+        self.count - self.seen_at[i] >= lag && self.previuos.as_ref() != Some(&self.seen[i])
+      })
+      .collect();
+
+    if candidates.is_empty() {
+      return self.next_seen();
+    }
+
+    let i = candidates[self.rng.next_with_upper_bound(candidates.len() as u32) as usize];
+    Ok(self.generate(self.seen[i].clone()))
+  }
+
+  /// Generates an element when the game is played in `GameMode::Window`. Seen elements are picked
+  /// among those generated within the last `k` commits. Unseen elements are either polled from
+  /// `unseen` or picked among the elements that has fallen out of the window.
+  fn next_in_window(&mut self, k: usize) -> Result<&T, GameError>
+  {
+    let (recent, expired): (Vec<usize>, Vec<usize>) = (0..self.seen.len())
+      .filter(|&i| self.previuos.as_ref() != Some(&self.seen[i]))
+      .partition(|&i| self.count - self.seen_at[i] <= k);
+
+    if !recent.is_empty() && self.rng.next_with_upper_bound(THRESHOLD_MAX) <= self.seen_threshold {
+      let i = recent[self.rng.next_with_upper_bound(recent.len() as u32) as usize];
+      return Ok(self.generate(self.seen[i].clone()));
+    }
+
+    let n = expired.len() + self.unseen.remaining();
+    if n == 0 {
+      return Err(GameError::UnseenEmpty);
+    }
+    let j = self.rng.next_with_upper_bound(n as u32) as usize;
+    if j < expired.len() {
+      Ok(self.generate(self.seen[expired[j]].clone()))
+    } else {
+      self.next_unseen()
+    }
+  }
+
+  /// Generates an element when the game is played in `GameMode::NBack`. A target, the element
+  /// generated `n` commits ago, is generated with the rate of the seen threshold. Otherwise it is
+  /// either a lure picked among the other recent elements or an element polled from `unseen`.
+  fn next_n_back(&mut self, n: usize) -> Result<&T, GameError>
+  {
+    if let Some(x) = self.n_back(n).cloned() {
+      if self.rng.next_with_upper_bound(THRESHOLD_MAX) <= self.seen_threshold {
+        return Ok(self.generate(x));
+      }
+    }
+
+    let target = self.n_back(n);
+    let lures: Vec<usize> = (0..self.recent.len())
+      .filter(|&i| Some(&self.recent[i]) != target)
+      .collect();
+
+    if lures.is_empty() || (self.unseen.remaining() > 0 && self.rng.next_with_upper_bound(2) == 0) {
+      self.next_unseen()
+    } else {
+      let i = lures[self.rng.next_with_upper_bound(lures.len() as u32) as usize];
+      Ok(self.generate(self.recent[i].clone()))
+    }
+  }
+
+  /// Sets `x` as the current element and adds it to the element checksum.
+  fn generate(&mut self, x: T) -> &T
+  {
+    self.element_checksum = KSINK::hash(self.element_checksum, x.as_ref());
+    self.current.insert(x)
+  }
+
   fn push_incorrect_commit(&mut self, x: usize) -> Option<&usize>
   {
     for y in self.incorrect_commits.iter_mut() {
@@ -267,31 +606,119 @@ where
   /// Commit the current result as unseen. Returns a `bool` indicatign if the element was unseen.
   pub fn commit_unseen(&mut self) -> Result<bool, GameError>
   {
-    self.commit(false)
+    self.single()?;
+    self.commit(false, None)
   }
 
   /// Commit the current result as seen. Returns a `bool` indicatign if the element was seen.
   pub fn commit_seen(&mut self) -> Result<bool, GameError>
   {
-    self.commit(true)
+    self.single()?;
+    self.commit(true, None)
+  }
+
+  /// Same as `Game::commit_unseen` but also stores how confident the player is.
+  pub fn commit_unseen_with_confidence(&mut self, confidence: Confidence)
+    -> Result<bool, GameError>
+  {
+    self.single()?;
+    self.commit(false, Some(confidence))
+  }
+
+  /// Same as `Game::commit_seen` but also stores how confident the player is.
+  pub fn commit_seen_with_confidence(&mut self, confidence: Confidence) -> Result<bool, GameError>
+  {
+    self.single()?;
+    self.commit(true, Some(confidence))
+  }
+
+  /// Commit with an optional confidence. For a pair, claiming seen is the same as choosing the
+  /// first element. Used when replaying a game, where the guess of a pair is recorded as a claim.
+  pub(crate) fn commit_with(
+    &mut self,
+    seen: bool,
+    confidence: Option<Confidence>,
+  ) -> Result<bool, GameError>
+  {
+    self.commit(seen, confidence)
+  }
+
+  /// Throws `GameError::UnsupportedByMode` if there is a pair waiting for `Game::commit_choice`.
+  fn single(&self) -> Result<(), GameError>
+  {
+    if self.alternative.is_some() {
+      Err(GameError::UnsupportedByMode)
+    } else {
+      Ok(())
+    }
   }
 
-  fn commit(&mut self, seen: bool) -> Result<bool, GameError>
+  /// Commit the choice of the element in the pair that is believed to be seen. `choice` is 0 for
+  /// the first element and 1 for the second. Returns a `bool` indicating if the choice was correct.
+  pub fn commit_choice(&mut self, choice: usize) -> Result<bool, GameError>
   {
     self.game_over()?;
 
-    if let Some(x) = self.current.take() {
-      let r = if !self.seen.contains(&x) {
+    if self.current.is_none() {
+      Err(GameError::EmptyCommit)
+    } else if self.alternative.is_none() {
+      Err(GameError::UnsupportedByMode)
+    } else if choice > 1 {
+      Err(GameError::InvalidChoice)
+    } else {
+      // Choosing the first element is the same as claiming that it is seen.
+      self.commit(choice == 0, None)
+    }
+  }
+
+  fn mark_seen(&mut self, x: &T)
+  {
+    match self.seen.iter().position(|y| y == x) {
+      Some(i) => self.seen_at[i] = self.count,
+      None => {
         self.seen.push(x.clone());
-        true
-      } else {
-        false
-      } ^ seen;
+        self.seen_at.push(self.count);
+      }
+    }
+  }
+
+  fn commit(&mut self, seen: bool, confidence: Option<Confidence>) -> Result<bool, GameError>
+  {
+    self.game_over()?;
+
+    let seen = seen && !self.is_opening();
+    if let Some(x) = self.current.take() {
+      let r = self.counts_as_seen(&x) == seen;
+
+      let reaction_time = self.reaction_time.take();
+      self.commit_checksum = KSINK::hash(self.commit_checksum, x.as_ref());
+      self.mark_seen(&x);
+      if let Some(y) = self.alternative.take() {
+        self.commit_checksum = KSINK::hash(self.commit_checksum, y.as_ref());
+        self.mark_seen(&y);
+      }
+      self.commit_checksum = KSINK::hash(self.commit_checksum, &[seen as u8]);
+      if let Some(t) = reaction_time {
+        self.commit_checksum = KSINK::hash(self.commit_checksum, &t.to_le_bytes());
+      }
+      if let GameMode::NBack(n) = self.mode {
+        // Keep one more than `n` so that the element generated `n + 1` commits ago can be a lure.
+        self.recent.push_back(x.clone());
+        if self.recent.len() > n + 1 {
+          self.recent.pop_front();
+        }
+      }
 
       self.previuos.replace(x);
+      self.guesses.push(seen);
+      self.confidences.push(confidence);
+      self.reaction_times.push(reaction_time);
       if !r {
         self.push_incorrect_commit(self.count);
       }
+      if self.mode == GameMode::Adaptive {
+        self.staircase.step(r);
+      }
       self.count += 1;
       Ok(r)
     } else {
@@ -357,3 +357,322 @@ fn element_checksum_outputs_as_expected()
     }
   }
 }
+
+#[test]
+fn adaptive_generates_equal_output_for_equal_input()
+{
+  let new_game = || {
+    Game::with_mode(
+      2954114961585323542,
+      0.5.try_into().unwrap(),
+      UnseenSetID::Unspecified,
+      (0..64).map(|x| [x]).collect(),
+      GameMode::Adaptive,
+    )
+  };
+  let mut game1 = new_game();
+  let mut game2 = new_game();
+
+  for i in 0..32 {
+    assert_eq!(game1.next(), game2.next());
+    if i % 5 == 0 {
+      assert_eq!(game1.commit_seen(), game2.commit_seen());
+    } else {
+      assert_eq!(game1.commit_unseen(), game2.commit_unseen());
+    }
+    assert_eq!(game1.staircase(), game2.staircase());
+    if game1.finished() {
+      break;
+    }
+  }
+}
+
+#[test]
+fn adaptive_raises_level_while_correct()
+{
+  let mut game = Game::with_mode(
+    16151870135063429427,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    GameMode::Adaptive,
+  );
+  let mut seen = HashSet::new();
+  let mut last = Vec::new();
+
+  for i in 0..24 {
+    let x = *game.next().unwrap();
+    let lag = game.staircase().lag();
+
+    if seen.contains(&x) {
+      // Seen elements must not be more recent than the lag allows, unless no element qualifies.
+      let j = last.iter().rposition(|y| y == &x).unwrap();
+      let qualifies = seen
+        .iter()
+        .any(|y| i - last.iter().rposition(|z| z == y).unwrap() >= lag);
+      assert!(i - j >= lag || !qualifies);
+      assert!(game.commit_seen().unwrap());
+    } else {
+      seen.insert(x);
+      assert!(game.commit_unseen().unwrap());
+    }
+    last.push(x);
+
+    let commits = last.len() as u32;
+    assert_eq!(game.staircase().level(), (commits / 2).min(8));
+  }
+}
+
+#[test]
+fn classic_never_updates_staircase()
+{
+  let mut game = Game::new(
+    5270139488620911470,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..16).map(|x| [x]).collect(),
+  );
+  for _ in 0..8 {
+    game.next().unwrap();
+    game.commit_unseen().unwrap();
+  }
+  assert_eq!(game.staircase().level(), 0);
+}
+
+#[test]
+fn window_counts_elements_outside_window_as_unseen()
+{
+  let k = 4;
+  let mut game = Game::with_mode(
+    4128312052867190093,
+    0.5.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..8).map(|x| [x]).collect(),
+    GameMode::Window(k),
+  );
+  let mut generated = Vec::new();
+  let mut reappeared = false;
+
+  for _ in 0..64 {
+    let x = *game.next().unwrap();
+    let last = generated.iter().rposition(|y| y == &x);
+    let seen = matches!(last, Some(j) if generated.len() - j <= k);
+    reappeared |= last.is_some() && !seen;
+
+    assert_eq!(game.is_current_seen(), Some(seen));
+    if seen {
+      assert!(game.commit_seen().unwrap());
+    } else {
+      assert!(game.commit_unseen().unwrap());
+    }
+    generated.push(x);
+  }
+
+  // With only 8 elements the game must have reused expired elements as unseen.
+  assert!(reappeared);
+  assert_eq!(game.lives(), INITIAL_LIVES_AMOUNT);
+}
+
+#[test]
+fn window_errors_when_unseen_and_window_is_empty()
+{
+  let mut game = Game::with_mode(
+    3092318153601880069,
+    0.0.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..1).map(|x| [x]).collect(),
+    GameMode::Window(4),
+  );
+  assert!(game.next().is_ok());
+  assert!(game.commit_unseen().unwrap());
+  assert_eq!(game.next(), Err(GameError::UnseenEmpty));
+}
+
+#[test]
+fn n_back_judges_against_element_n_commits_ago()
+{
+  let n = 2;
+  let mut game = Game::with_mode(
+    9951560398434006325,
+    0.3.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    GameMode::NBack(n),
+  );
+  let mut generated = Vec::new();
+  let mut targets = 0;
+  let mut lures = 0;
+
+  for i in 0..48 {
+    let x = *game.next().unwrap();
+    let target = i >= n && generated[i - n] == x;
+    targets += target as usize;
+    lures += (!target && generated.contains(&x)) as usize;
+
+    assert_eq!(game.is_current_seen(), Some(target));
+    if target {
+      assert!(game.commit_seen().unwrap());
+    } else {
+      assert!(game.commit_unseen().unwrap());
+    }
+    generated.push(x);
+  }
+
+  assert!(targets > 0);
+  assert!(lures > 0);
+  assert_eq!(game.score(), 48);
+}
+
+#[test]
+fn n_back_generates_equal_output_for_equal_input()
+{
+  let new_game = || {
+    Game::with_mode(
+      2224757960227010227,
+      0.4.try_into().unwrap(),
+      UnseenSetID::Unspecified,
+      (0..32).map(|x| [x]).collect(),
+      GameMode::NBack(3),
+    )
+  };
+  let mut game1 = new_game();
+  let mut game2 = new_game();
+
+  for i in 0..24 {
+    if game1.finished() {
+      break;
+    }
+    assert_eq!(game1.next(), game2.next());
+    if i % 3 == 0 {
+      assert_eq!(game1.commit_seen(), game2.commit_seen());
+    } else {
+      assert_eq!(game1.commit_unseen(), game2.commit_unseen());
+    }
+  }
+}
+
+#[test]
+fn forced_choice_pairs_have_exactly_one_seen()
+{
+  let mut game = Game::with_mode(
+    6841907151225420571,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    GameMode::ForcedChoice,
+  );
+  assert_eq!(game.next_pair(), Err(GameError::UnsupportedByMode));
+
+  let mut seen = HashSet::new();
+  seen.insert(*game.next().unwrap());
+  assert!(game.commit_unseen().unwrap());
+  assert!(game.expects_pair());
+  assert_eq!(game.next(), Err(GameError::UnsupportedByMode));
+
+  for _ in 0..24 {
+    let (x, y) = game.next_pair().map(|(x, y)| (*x, *y)).unwrap();
+    assert!(seen.contains(&x) ^ seen.contains(&y));
+    assert_eq!(game.is_current_seen(), Some(seen.contains(&x)));
+    assert_eq!(game.commit_seen(), Err(GameError::UnsupportedByMode));
+    assert_eq!(game.commit_choice(2), Err(GameError::InvalidChoice));
+
+    let choice = if seen.contains(&x) { 0 } else { 1 };
+    assert!(game.commit_choice(choice).unwrap());
+    seen.insert(x);
+    seen.insert(y);
+  }
+  assert_eq!(game.score(), 24);
+}
+
+#[test]
+fn forced_choice_opening_is_not_scored()
+{
+  let mut game = Game::with_mode(
+    6841907151225420571,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    GameMode::ForcedChoice,
+  );
+  game.next().unwrap();
+  assert!(game.commit_seen().unwrap());
+  assert_eq!(game.lives(), INITIAL_LIVES_AMOUNT);
+  assert_eq!(game.score(), 0);
+  assert_eq!(game.guesses(), [false]);
+
+  game.next_pair().unwrap();
+  let choice = if game.is_current_seen().unwrap() {
+    0
+  } else {
+    1
+  };
+  assert!(game.commit_choice(choice).unwrap());
+  assert_eq!(game.score(), 1);
+}
+
+#[test]
+fn forced_choice_wrong_choice_costs_a_life()
+{
+  let mut game = Game::with_mode(
+    15412870958317722829,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..16).map(|x| [x]).collect(),
+    GameMode::ForcedChoice,
+  );
+  game.next().unwrap();
+  game.commit_unseen().unwrap();
+
+  for lives in [2, 1, 0] {
+    game.next_pair().unwrap();
+    let choice = if game.is_current_seen().unwrap() {
+      1
+    } else {
+      0
+    };
+    assert!(!game.commit_choice(choice).unwrap());
+    assert_eq!(game.lives(), lives);
+  }
+  assert!(game.finished());
+  assert_eq!(game.next_pair(), Err(GameError::GameOver));
+}
+
+#[test]
+fn commit_choice_errors_without_pair()
+{
+  let mut game = Game::new(
+    301474391813545867,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..16).map(|x| [x]).collect(),
+  );
+  assert_eq!(game.commit_choice(0), Err(GameError::EmptyCommit));
+  game.next().unwrap();
+  assert_eq!(game.commit_choice(0), Err(GameError::UnsupportedByMode));
+}
+
+#[test]
+fn confidences_are_stored_for_each_commit()
+{
+  let mut game = Game::new(
+    12139066373524883343,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..16).map(|x| [x]).collect(),
+  );
+  game.next().unwrap();
+  game
+    .commit_unseen_with_confidence(Confidence::Sure)
+    .unwrap();
+  game.next().unwrap();
+  game.commit_unseen().unwrap();
+  game.next().unwrap();
+  game.commit_seen_with_confidence(Confidence::Guess).unwrap();
+
+  assert_eq!(
+    game.confidences(),
+    &[Some(Confidence::Sure), None, Some(Confidence::Guess)]
+  );
+  game.reset();
+  assert!(game.confidences().is_empty());
+}
@@ -7,6 +7,8 @@ pub enum GameError
   EmptyCommit,
   NextCalledWithUncommitedResult,
   GameOver,
+  UnsupportedByMode,
+  InvalidChoice,
 }
 
 // TODO:
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod test;
+
+use schemars::JsonSchema;
+use serde::{
+  Deserialize,
+  Serialize,
+};
+
+/// Highest difficulty level a `Staircase` can reach.
+const STAIRCASE_MAX_LEVEL: u32 = 8;
+
+/// The amount the minimum repetition lag grows with for each level of a `Staircase`.
+const STAIRCASE_LAG_STEP: usize = 2;
+
+// -------------------------------------------------------------------------------------------------
+// GameMode
+// -------------------------------------------------------------------------------------------------
+
+/// The rules deciding how the elements of a `Game` are generated.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub enum GameMode
+{
+  /// The seen threshold is fixed for the whole game.
+  #[default]
+  Classic,
+
+  /// The seen threshold and the repetition lag follows a `Staircase` driven by the correctness of
+  /// the commits.
+  Adaptive,
+
+  /// An element only counts as seen if it was generated within the given amount of commits. Older
+  /// elements counts as unseen again and can be generated as such.
+  Window(usize),
+
+  /// An element counts as seen if it is the same as the element generated the given amount of
+  /// commits ago. The seen threshold is the rate of such targets.
+  NBack(usize),
+
+  /// Two elements are generated with `Game::next_pair`, where exactly one of them is seen, and the
+  /// player picks the seen one with `Game::commit_choice`. Since the first pair requires a seen
+  /// element the game starts with a single unseen element generated with `Game::next`. That
+  /// element is only shown, it is committed like any other but never scored.
+  ForcedChoice,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Staircase
+// -------------------------------------------------------------------------------------------------
+
+/// A 2-down/1-up staircase. Two correct commits in a row raises the level by one and an incorrect
+/// commit lowers it by one. The level settles where the player is correct ~70.7% of the time.
+///
+/// The state only depends on the sequence of correct and incorrect commits, which makes it
+/// possible to reproduce it from the incorrect commits of a `GameOver`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Staircase
+{
+  level: u32,
+  streak: u32,
+}
+
+impl Staircase
+{
+  /// Updates the staircase with the result of a commit.
+  pub fn step(&mut self, correct: bool)
+  {
+    if correct {
+      self.streak += 1;
+      if self.streak == 2 {
+        self.streak = 0;
+        self.level = (self.level + 1).min(STAIRCASE_MAX_LEVEL);
+      }
+    } else {
+      self.streak = 0;
+      self.level = self.level.saturating_sub(1);
+    }
+  }
+
+  /// The current difficulty level, in range [0, 8].
+  pub fn level(&self) -> u32
+  {
+    self.level
+  }
+
+  /// The seen threshold at the current level. At the highest level the threshold is half of
+  /// `base`, making repeated elements rarer and further apart.
+  pub fn seen_threshold(&self, base: u32) -> u32
+  {
+    base - (base as u64 * self.level as u64 / (2 * STAIRCASE_MAX_LEVEL as u64)) as u32
+  }
+
+  /// The minimum amount of commits since a seen element was last generated before it can be
+  /// generated again.
+  pub fn lag(&self) -> usize
+  {
+    self.level as usize * STAIRCASE_LAG_STEP
+  }
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn two_correct_raises_level_and_one_incorrect_lowers_it()
+{
+  let mut staircase = Staircase::default();
+  for (level, correct) in [
+    (0, true),
+    (1, true),
+    (1, true),
+    (2, true),
+    (1, false),
+    (1, true),
+    (0, false),
+    (0, false),
+  ] {
+    staircase.step(correct);
+    assert_eq!(staircase.level(), level);
+  }
+}
+
+#[test]
+fn level_never_exceeds_max()
+{
+  let mut staircase = Staircase::default();
+  for _ in 0..64 {
+    staircase.step(true);
+  }
+  assert_eq!(staircase.level(), STAIRCASE_MAX_LEVEL);
+  assert_eq!(staircase.seen_threshold(400), 200);
+  assert_eq!(staircase.lag(), 16);
+}
@@ -58,6 +58,12 @@ impl<T> Unseen<T>
     }
   }
 
+  /// The amount of elements that are left to poll.
+  pub fn remaining(&self) -> usize
+  {
+    self.size
+  }
+
   pub fn reset(&mut self)
   {
     self.size = self.data.len();
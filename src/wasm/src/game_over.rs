@@ -1,11 +1,21 @@
+mod export;
 mod game_over;
+mod ghost_race;
+mod stats;
 
 #[cfg(test)]
 mod test;
 
+pub use export::ReplayRow;
 pub use game_over::{
   Commit,
   GameOver,
   GameOverIterator,
   SeenUnseen,
 };
+pub use ghost_race::{
+  GhostRace,
+  Step,
+  StepDiff,
+};
+pub use stats::Stats;
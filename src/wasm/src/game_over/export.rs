@@ -0,0 +1,199 @@
+use std::{
+  fmt::{
+    Display,
+    Write,
+  },
+  iter,
+};
+
+use serde::Serialize;
+
+use crate::{
+  game::Confidence,
+  game_over::{
+    GameOver,
+    SeenUnseen,
+  },
+};
+
+const HEADER: [&str; 7] = [
+  "index", "element", "actual", "guess", "correct", "lives", "score",
+];
+
+// -------------------------------------------------------------------------------------------------
+// ReplayRow
+// -------------------------------------------------------------------------------------------------
+
+/// A commit of a replayed game together with the lives left and the score right after it. The
+/// confidence and reaction time are only part of the JSON export.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ReplayRow<T>
+{
+  pub index: usize,
+  pub element: T,
+  /// The second element of the pair if the commit was a choice between two elements.
+  pub alternative: Option<T>,
+  pub actual: SeenUnseen,
+  pub guess: SeenUnseen,
+  pub correct: bool,
+  pub confidence: Option<Confidence>,
+  /// The time in milliseconds it took to answer, if it was measured.
+  pub reaction_time: Option<u32>,
+  pub lives: usize,
+  pub score: usize,
+}
+
+impl<T> ReplayRow<T>
+where
+  T: Display,
+{
+  /// The cells of the row in the order of `HEADER`, with the pair of a choice in a single cell.
+  fn cells(&self) -> [String; 7]
+  {
+    [
+      self.index.to_string(),
+      match &self.alternative {
+        Some(y) => format!("{} / {}", self.element, y),
+        None => self.element.to_string(),
+      },
+      seen_unseen(&self.actual).to_string(),
+      seen_unseen(&self.guess).to_string(),
+      if self.correct { "✓" } else { "✗" }.to_string(),
+      self.lives.to_string(),
+      self.score.to_string(),
+    ]
+  }
+}
+
+fn seen_unseen(x: &SeenUnseen) -> &'static str
+{
+  match x {
+    SeenUnseen::Seen => "seen",
+    SeenUnseen::Unseen => "unseen",
+  }
+}
+
+/// `s` as a CSV field, quoted if it contains a separator, quote or line break.
+fn csv_field(s: &str) -> String
+{
+  if s.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", s.replace('"', "\"\""))
+  } else {
+    s.to_string()
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Export
+// -------------------------------------------------------------------------------------------------
+
+impl<T> GameOver<T>
+where
+  T: Clone + PartialEq + AsRef<[u8]>,
+{
+  /// Replays the game with one row per commit.
+  pub fn rows(&self) -> Vec<ReplayRow<T>>
+  {
+    let mut iterator = self.iter();
+    iter::from_fn(|| {
+      let commit = iterator.next()?;
+      Some((commit, iterator.lives(), iterator.score()))
+    })
+    .enumerate()
+    .map(|(index, (commit, lives, score))| ReplayRow {
+      index,
+      element: commit.element().clone(),
+      alternative: commit.alternative().cloned(),
+      actual: commit.actual().clone(),
+      guess: commit.guess().clone(),
+      correct: commit.correct(),
+      confidence: commit.confidence(),
+      reaction_time: commit.reaction_time(),
+      lives,
+      score,
+    })
+    .collect()
+  }
+
+  /// The rows of the replay as JSON.
+  pub fn to_json(&self) -> Result<String, serde_json::Error>
+  where
+    T: Serialize,
+  {
+    serde_json::to_string(&self.rows())
+  }
+}
+
+impl<T> GameOver<T>
+where
+  T: Clone + PartialEq + AsRef<[u8]> + Display,
+{
+  /// The rows of the replay as a plain-text table with aligned columns.
+  pub fn to_text_table(&self) -> String
+  {
+    let rows: Vec<_> = iter::once(HEADER.map(str::to_string))
+      .chain(self.rows().iter().map(ReplayRow::cells))
+      .collect();
+    let mut widths = [0; HEADER.len()];
+    for row in &rows {
+      for (width, cell) in widths.iter_mut().zip(row) {
+        *width = (*width).max(cell.chars().count());
+      }
+    }
+
+    let mut s = String::new();
+    for row in rows {
+      let line = row
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(i, (cell, width))| match i {
+          // Numbers are right aligned.
+          0 | 5 | 6 => format!("{:>width$}", cell, width = width),
+          _ => format!("{:<width$}", cell, width = width),
+        })
+        .collect::<Vec<_>>()
+        .join("  ");
+      writeln!(s, "{}", line.trim_end()).unwrap();
+    }
+    s
+  }
+
+  /// The rows of the replay as a Markdown table.
+  pub fn to_markdown_table(&self) -> String
+  {
+    let mut s = format!("| {} |\n", HEADER.join(" | "));
+    writeln!(s, "| ---: | --- | --- | --- | :---: | ---: | ---: |").unwrap();
+    for row in self.rows() {
+      let cells = row.cells().map(|x| x.replace('|', "\\|"));
+      writeln!(s, "| {} |", cells.join(" | ")).unwrap();
+    }
+    s
+  }
+
+  /// The rows of the replay as CSV with a header line. Unlike the tables, the second element of a
+  /// pair has a column of its own.
+  pub fn to_csv(&self) -> String
+  {
+    let mut s = String::from("index,element,alternative,actual,guess,correct,lives,score\n");
+    for row in self.rows() {
+      writeln!(
+        s,
+        "{},{},{},{},{},{},{},{}",
+        row.index,
+        csv_field(&row.element.to_string()),
+        row
+          .alternative
+          .map(|x| csv_field(&x.to_string()))
+          .unwrap_or_default(),
+        seen_unseen(&row.actual),
+        seen_unseen(&row.guess),
+        row.correct,
+        row.lives,
+        row.score
+      )
+      .unwrap();
+    }
+    s
+  }
+}
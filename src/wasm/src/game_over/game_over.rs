@@ -1,19 +1,26 @@
 use std::iter;
 
+use serde::Serialize;
+
 use crate::{
   coder::UnseenSetID,
   game::{
+    Confidence,
     Game,
+    GameError,
+    GameMode,
     IncorrectCommits,
     SeenThreshold,
   },
+  game_over::Stats,
 };
 
 // -------------------------------------------------------------------------------------------------
 // Commit
 // -------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SeenUnseen
 {
   Seen,
@@ -31,10 +38,32 @@ pub struct Commit<T>
   element: T,
   actual: SeenUnseen,
   guess: SeenUnseen,
+  alternative: Option<T>,
+  confidence: Option<Confidence>,
+  reaction_time: Option<u32>,
 }
 
 impl<T> Commit<T>
 {
+  pub(super) fn new(
+    element: T,
+    actual: SeenUnseen,
+    guess: SeenUnseen,
+    alternative: Option<T>,
+    confidence: Option<Confidence>,
+    reaction_time: Option<u32>,
+  ) -> Commit<T>
+  {
+    Commit {
+      element,
+      actual,
+      guess,
+      alternative,
+      confidence,
+      reaction_time,
+    }
+  }
+
   /// The element generated for the commit.
   pub fn element(&self) -> &T
   {
@@ -58,6 +87,26 @@ impl<T> Commit<T>
   {
     self.actual == self.guess
   }
+
+  /// The second element of the pair if the commit was a choice between two elements. For such a
+  /// commit `actual` is `Seen` if `element` is the seen one of the pair and `guess` is `Seen` if the
+  /// player chose `element`.
+  pub fn alternative(&self) -> Option<&T>
+  {
+    self.alternative.as_ref()
+  }
+
+  /// How confident the player was in the guess, if it was given.
+  pub fn confidence(&self) -> Option<Confidence>
+  {
+    self.confidence
+  }
+
+  /// The time in milliseconds it took to answer, if it was measured.
+  pub fn reaction_time(&self) -> Option<u32>
+  {
+    self.reaction_time
+  }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -86,11 +135,64 @@ where
     unseen: Vec<T>,
     seen_threshold: SeenThreshold,
     incorrect_commits: IncorrectCommits,
-  ) -> GameOver<T>
+  ) -> Result<GameOver<T>, GameError>
+  {
+    GameOver::with_mode(
+      seed,
+      unseen_set_id,
+      unseen,
+      seen_threshold,
+      GameMode::Classic,
+      incorrect_commits,
+    )
+  }
+
+  /// Replays a game played with the rules of `mode`. Throws a `GameError` if the incorrect
+  /// commits do not make a game that can be played to its end, e.g. if the unseen elements run
+  /// out before the lives do.
+  pub fn with_mode(
+    seed: u64,
+    unseen_set_id: UnseenSetID,
+    unseen: Vec<T>,
+    seen_threshold: SeenThreshold,
+    mode: GameMode,
+    incorrect_commits: IncorrectCommits,
+  ) -> Result<GameOver<T>, GameError>
   {
-    let game = Game::new(seed, seen_threshold, unseen_set_id, unseen);
+    let game = Game::with_mode(seed, seen_threshold, unseen_set_id, unseen, mode)
+      .with_lives(incorrect_commits.len());
     let mut iterator = GameOver(game).into_iter();
-    iterator.incorrect_commits = incorrect_commits;
+    iterator.guesses = Guesses::Incorrect(incorrect_commits);
+    iterator.commit_count = usize::MAX;
+    iterator.replay().map(GameOver)
+  }
+
+  /// Replays `game` with the guess of each commit, see `Game::guesses`. Unlike the incorrect
+  /// commits, the guesses determine the game even if its rules depend on more than whether each
+  /// guess was correct. Stops when the game is over or the guesses run out. Throws a `GameError`
+  /// if the game can not make as many commits as there are guesses.
+  pub fn with_guesses(game: Game<T>, guesses: Vec<bool>) -> Result<GameOver<T>, GameError>
+  {
+    let mut iterator = GameOver(game).into_iter();
+    iterator.commit_count = guesses.len();
+    iterator.guesses = Guesses::Recorded(guesses);
+    iterator.replay().map(GameOver)
+  }
+
+  /// Replays the game with the confidence given for each commit.
+  pub fn with_confidences(self, confidences: Vec<Option<Confidence>>) -> GameOver<T>
+  {
+    let mut iterator = self.into_iter();
+    iterator.confidences = confidences;
+    iterator.all(|_| true);
+    GameOver(iterator.game)
+  }
+
+  /// Replays the game with the reaction time of each commit.
+  pub fn with_reaction_times(self, reaction_times: Vec<Option<u32>>) -> GameOver<T>
+  {
+    let mut iterator = self.into_iter();
+    iterator.reaction_times = reaction_times;
     iterator.all(|_| true);
     GameOver(iterator.game)
   }
@@ -114,6 +216,12 @@ impl<T> GameOver<T>
     self.0.score()
   }
 
+  /// The amount of lives the game started with.
+  pub fn initial_lives(&self) -> usize
+  {
+    self.0.initial_lives()
+  }
+
   /// Lives left when the game finished.
   ///
   /// NOTE:
@@ -130,6 +238,12 @@ impl<T> GameOver<T>
     self.0.element_checksum()
   }
 
+  /// The running checksum of the commits.
+  pub fn commit_checksum(&self) -> u64
+  {
+    self.0.commit_checksum()
+  }
+
   pub fn unseen_set_id(&self) -> &UnseenSetID
   {
     self.0.unseen_set_id()
@@ -139,20 +253,66 @@ impl<T> GameOver<T>
   {
     self.0.seen_threshold()
   }
+
+  /// The `GameMode` the game was played with.
+  pub fn mode(&self) -> &GameMode
+  {
+    self.0.mode()
+  }
+
+  /// The guess of each commit, see `Game::guesses`.
+  pub fn guesses(&self) -> &[bool]
+  {
+    self.0.guesses()
+  }
+
+  /// The confidence given for each commit.
+  pub fn confidences(&self) -> &[Option<Confidence>]
+  {
+    self.0.confidences()
+  }
+
+  /// The reaction time of each commit.
+  pub fn reaction_times(&self) -> &[Option<u32>]
+  {
+    self.0.reaction_times()
+  }
+
+  /// The amount of commits made in the game.
+  pub fn commit_count(&self) -> usize
+  {
+    self.0.commit_count()
+  }
 }
 
 // -------------------------------------------------------------------------------------------------
 // Iterator
 // -------------------------------------------------------------------------------------------------
 
+/// Where a `GameOverIterator` takes the guess of each commit from.
+#[derive(Debug)]
+enum Guesses
+{
+  // Every guess is correct except for the commits at these indices. Only determines games whose
+  // rules depend on nothing but whether each guess was correct.
+  Incorrect(IncorrectCommits),
+
+  // The guess of each commit.
+  Recorded(Vec<bool>),
+}
+
 /// Iterates over the commits done in a `Game`.
 #[derive(Debug)]
 pub struct GameOverIterator<T>
 {
   game: Game<T>,
   index: usize,
-  incorrect_commits: IncorrectCommits,
-  seen: Vec<T>,
+  // The amount of commits to replay, which is less than needed to finish the game if it was ended
+  // early.
+  commit_count: usize,
+  guesses: Guesses,
+  confidences: Vec<Option<Confidence>>,
+  reaction_times: Vec<Option<u32>>,
 }
 
 impl<T> Iterator for GameOverIterator<T>
@@ -161,67 +321,120 @@ where
 {
   type Item = Commit<T>;
 
+  /// A `GameOver` that has been replayed once replays the same way, so iterating over one never
+  /// ends early.
   fn next(&mut self) -> Option<Self::Item>
+  {
+    self.try_next().ok().flatten()
+  }
+}
+
+impl<T> GameOverIterator<T>
+where
+  T: Clone + PartialEq + AsRef<[u8]>,
+{
+  /// The next commit, or `None` when the game is over or all commits are replayed. Throws a
+  /// `GameError` if the game can not make the next commit.
+  fn try_next(&mut self) -> Result<Option<Commit<T>>, GameError>
   {
     use SeenUnseen::*;
 
-    if self.game.finished() {
-      None
+    if self.game.finished() || self.index >= self.commit_count {
+      return Ok(None);
+    }
+
+    let (element, alternative) = if self.game.expects_pair() {
+      let (x, y) = self.game.next_pair()?;
+      (x.clone(), Some(y.clone()))
     } else {
-      let element = self.game.next().unwrap().clone();
-
-      if self.seen.contains(&element) {
-        Some(Commit {
-          element,
-          actual: Seen,
-          guess: if self.incorrect_commits.contains(&Some(self.index)) {
-            self.index += 1;
-            self.game.commit_unseen().unwrap();
-            Unseen
-          } else {
-            self.index += 1;
-            self.game.commit_seen().unwrap();
-            Seen
-          },
-        })
-      } else {
-        self.seen.push(element.clone());
-        Some(Commit {
-          element,
-          actual: Unseen,
-          guess: if self.incorrect_commits.contains(&Some(self.index)) {
-            self.index += 1;
-            self.game.commit_seen().unwrap();
-            Seen
-          } else {
-            self.index += 1;
-            self.game.commit_unseen().unwrap();
-            Unseen
-          },
-        })
+      (self.game.next()?.clone(), None)
+    };
+
+    let actual_seen = self.game.is_current_seen().ok_or(GameError::EmptyCommit)?;
+    let guess_seen = match &self.guesses {
+      Guesses::Incorrect(incorrect_commits) => {
+        actual_seen ^ incorrect_commits.contains(&Some(self.index))
       }
+      Guesses::Recorded(guesses) => *guesses.get(self.index).ok_or(GameError::EmptyCommit)?,
+    };
+    let confidence = self.confidences.get(self.index).cloned().flatten();
+    let reaction_time = self.reaction_times.get(self.index).cloned().flatten();
+    self.index += 1;
+    if let Some(t) = reaction_time {
+      self.game.set_reaction_time(t);
     }
+    self.game.commit_with(guess_seen, confidence)?;
+
+    Ok(Some(Commit {
+      element,
+      actual: if actual_seen { Seen } else { Unseen },
+      guess: if guess_seen { Seen } else { Unseen },
+      alternative,
+      confidence,
+      reaction_time,
+    }))
+  }
+
+  /// Replays every remaining commit and returns the game.
+  fn replay(mut self) -> Result<Game<T>, GameError>
+  {
+    while self.try_next()?.is_some() {}
+    Ok(self.game)
+  }
+}
+
+impl<T> GameOverIterator<T>
+{
+  /// Lives left after the commits returned so far.
+  pub fn lives(&self) -> usize
+  {
+    self.game.lives()
+  }
+
+  /// Score after the commits returned so far.
+  pub fn score(&self) -> usize
+  {
+    self.game.score()
   }
 }
 
 impl<T> GameOver<T>
 where
-  T: Clone + PartialEq,
+  T: Clone + PartialEq + AsRef<[u8]>,
 {
   pub fn iter(&self) -> GameOverIterator<T>
   {
     self.clone().into_iter()
   }
 
-  pub fn into_iter(mut self) -> GameOverIterator<T>
+  /// Signal detection statistics of the commits.
+  pub fn stats(&self) -> Stats
+  {
+    self.iter().collect()
+  }
+}
+
+impl<T> IntoIterator for GameOver<T>
+where
+  T: Clone + PartialEq + AsRef<[u8]>,
+{
+  type Item = Commit<T>;
+  type IntoIter = GameOverIterator<T>;
+
+  fn into_iter(mut self) -> GameOverIterator<T>
   {
-    let incorrect_commits = self.0.incorrect_commits().clone();
+    let guesses = Guesses::Recorded(self.0.guesses().to_vec());
+    let confidences = self.0.confidences().to_vec();
+    let reaction_times = self.0.reaction_times().to_vec();
+    let commit_count = self.0.commit_count();
     self.0.reset();
     GameOverIterator {
       game: self.0,
       index: 0,
-      incorrect_commits,
-      seen: Vec::new(),
+      commit_count,
+      guesses,
+      confidences,
+      reaction_times,
     }
   }
 }
@@ -0,0 +1,228 @@
+use crate::{
+  game::{
+    Game,
+    GameError,
+  },
+  game_over::{
+    Commit,
+    GameOver,
+    SeenUnseen,
+  },
+};
+
+// -------------------------------------------------------------------------------------------------
+// Step
+// -------------------------------------------------------------------------------------------------
+
+/// The outcome of a commit in a `GhostRace`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step
+{
+  /// The index of the commit.
+  pub index: usize,
+
+  /// True if the live commit was correct.
+  pub correct: bool,
+
+  /// True if the ghost was correct at the same index. `None` if the ghost was already out of
+  /// lives.
+  pub ghost_correct: Option<bool>,
+
+  /// True if the ghost was shown the same element, which is always the case unless the mode is
+  /// `GameMode::Adaptive`.
+  pub same_element: bool,
+
+  /// Lives left after the commit.
+  pub lives: usize,
+
+  /// Lives the ghost had left after the commit at the same index.
+  pub ghost_lives: usize,
+}
+
+// -------------------------------------------------------------------------------------------------
+// StepDiff
+// -------------------------------------------------------------------------------------------------
+
+/// The live commit and the ghost commit at the same index.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepDiff<T>
+{
+  pub index: usize,
+  pub live: Option<Commit<T>>,
+  pub ghost: Option<Commit<T>>,
+  pub lives: usize,
+  pub ghost_lives: usize,
+}
+
+impl<T> StepDiff<T>
+where
+  T: PartialEq,
+{
+  /// True if only one of the games made the commit, if the elements differ or if only one of them
+  /// was correct.
+  pub fn diverges(&self) -> bool
+  {
+    match (&self.live, &self.ghost) {
+      (Some(x), Some(y)) => x.element() != y.element() || x.correct() != y.correct(),
+      _ => true,
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// GhostRace
+// -------------------------------------------------------------------------------------------------
+
+/// A live `Game` played against the replay of a `GameOver`, the ghost, with the same seed, seen
+/// threshold, unseen set and mode.
+pub struct GhostRace<T>
+{
+  game: Game<T>,
+  ghost: Vec<Commit<T>>,
+  commits: Vec<Commit<T>>,
+  // The element, or pair of elements, waiting to be committed.
+  pending: Option<(T, Option<T>)>,
+}
+
+impl<T> GhostRace<T>
+where
+  T: Clone + PartialEq + AsRef<[u8]>,
+{
+  /// A race against `ghost` where the live game picks elements from `unseen`, which should be the
+  /// elements the ghost was played with.
+  pub fn new(ghost: &GameOver<T>, unseen: Vec<T>) -> GhostRace<T>
+  {
+    GhostRace {
+      game: Game::with_mode(
+        ghost.seed(),
+        ghost.seen_threshold(),
+        ghost.unseen_set_id().clone(),
+        unseen,
+        ghost.mode().clone(),
+      )
+      .with_lives(ghost.initial_lives()),
+      ghost: ghost.iter().collect(),
+      commits: Vec::new(),
+      pending: None,
+    }
+  }
+
+  /// The live game.
+  pub fn game(&self) -> &Game<T>
+  {
+    &self.game
+  }
+
+  /// Ends the race and returns the live game.
+  pub fn into_game(self) -> Game<T>
+  {
+    self.game
+  }
+
+  /// The commits of the ghost.
+  pub fn ghost(&self) -> &[Commit<T>]
+  {
+    &self.ghost
+  }
+
+  /// The live commits so far.
+  pub fn commits(&self) -> &[Commit<T>]
+  {
+    &self.commits
+  }
+
+  /// Generates the next element of the live game. See `Game::next`.
+  pub fn next_element(&mut self) -> Result<&T, GameError>
+  {
+    let x = self.game.next()?.clone();
+    Ok(&self.pending.insert((x, None)).0)
+  }
+
+  /// Generates the next pair of elements of the live game. See `Game::next_pair`.
+  pub fn next_pair(&mut self) -> Result<(&T, &T), GameError>
+  {
+    let (x, y) = self.game.next_pair()?;
+    let (x, y) = (x.clone(), y.clone());
+    let (x, y) = self.pending.insert((x, Some(y)));
+    Ok((x, y.as_ref().unwrap()))
+  }
+
+  pub fn commit_seen(&mut self) -> Result<Step, GameError>
+  {
+    self.commit(|game| game.commit_seen())
+  }
+
+  pub fn commit_unseen(&mut self) -> Result<Step, GameError>
+  {
+    self.commit(|game| game.commit_unseen())
+  }
+
+  pub fn commit_choice(&mut self, choice: usize) -> Result<Step, GameError>
+  {
+    self.commit(|game| game.commit_choice(choice))
+  }
+
+  fn commit<F>(&mut self, f: F) -> Result<Step, GameError>
+  where
+    F: FnOnce(&mut Game<T>) -> Result<bool, GameError>,
+  {
+    use SeenUnseen::*;
+
+    let actual_seen = self.game.is_current_seen().ok_or(GameError::EmptyCommit)?;
+    let correct = f(&mut self.game)?;
+    let (element, alternative) = self.pending.take().ok_or(GameError::EmptyCommit)?;
+    let guess_seen = actual_seen == correct;
+
+    let index = self.commits.len();
+    self.commits.push(Commit::new(
+      element,
+      if actual_seen { Seen } else { Unseen },
+      if guess_seen { Seen } else { Unseen },
+      alternative,
+      None,
+      self.game.reaction_times().last().cloned().flatten(),
+    ));
+
+    let ghost = self.ghost.get(index);
+    Ok(Step {
+      index,
+      correct,
+      ghost_correct: ghost.map(|x| x.correct()),
+      same_element: ghost.is_some_and(|x| x.element() == self.commits[index].element()),
+      lives: self.game.lives(),
+      ghost_lives: lives_after(self.game.initial_lives(), &self.ghost, index),
+    })
+  }
+
+  /// The commits of both games side by side, covering the longest of them.
+  pub fn diff(&self) -> Vec<StepDiff<T>>
+  {
+    (0..self.commits.len().max(self.ghost.len()))
+      .map(|i| StepDiff {
+        index: i,
+        live: self.commits.get(i).cloned(),
+        ghost: self.ghost.get(i).cloned(),
+        lives: lives_after(self.game.initial_lives(), &self.commits, i),
+        ghost_lives: lives_after(self.game.initial_lives(), &self.ghost, i),
+      })
+      .collect()
+  }
+
+  /// The index of the first commit after which the live game and the ghost had different amounts
+  /// of lives left.
+  pub fn lives_diverged_at(&self) -> Option<usize>
+  {
+    self.diff().iter().position(|x| x.lives != x.ghost_lives)
+  }
+}
+
+/// The lives left after commit `index` of `commits` in a game started with `lives`.
+fn lives_after<T>(lives: usize, commits: &[Commit<T>], index: usize) -> usize
+{
+  let incorrect = commits
+    .iter()
+    .take(index + 1)
+    .filter(|x| !x.correct())
+    .count();
+  lives.saturating_sub(incorrect)
+}
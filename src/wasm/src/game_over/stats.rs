@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod test;
+
+use std::iter::FromIterator;
+
+use crate::{
+  game::Confidence,
+  game_over::{
+    Commit,
+    SeenUnseen,
+  },
+};
+
+/// Amount of ratings a commit can be given. From most confident seen to most confident unseen.
+const RATINGS: usize = 6;
+
+/// Signal detection statistics of the commits in a game. Commits that are a choice between two
+/// elements are not included.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stats
+{
+  seen_ratings: [usize; RATINGS],
+  unseen_ratings: [usize; RATINGS],
+}
+
+/// Rates a commit on a scale from 0, sure it is seen, to 5, sure it is unseen. Commits without a
+/// confidence are rated as `Confidence::Think`.
+fn rating(guess: &SeenUnseen, confidence: Option<Confidence>) -> usize
+{
+  let i = u8::from(confidence.unwrap_or(Confidence::Think)) as usize - 1;
+  match guess {
+    SeenUnseen::Seen => i,
+    SeenUnseen::Unseen => RATINGS - 1 - i,
+  }
+}
+
+/// The inverse of the standard normal cumulative distribution function for `p` in range (0, 1).
+///
+/// Peter Acklam's rational approximation, with a relative error less than 1.15e-9.
+fn probit(p: f64) -> f64
+{
+  const A: [f64; 6] = [
+    -3.969683028665376e+01,
+    2.209460984245205e+02,
+    -2.759285104469687e+02,
+    1.38357751867269e+02,
+    -3.066479806614716e+01,
+    2.506628277459239e+00,
+  ];
+  const B: [f64; 5] = [
+    -5.447609879822406e+01,
+    1.615858368580409e+02,
+    -1.556989798598866e+02,
+    6.680131188771972e+01,
+    -1.328068155288572e+01,
+  ];
+  const C: [f64; 6] = [
+    -7.784894002430293e-03,
+    -3.223964580411365e-01,
+    -2.400758277161838e+00,
+    -2.549732539343734e+00,
+    4.374664141464968e+00,
+    2.938163982698783e+00,
+  ];
+  const D: [f64; 4] = [
+    7.784695709041462e-03,
+    3.224671290700398e-01,
+    2.445134137142996e+00,
+    3.754408661907416e+00,
+  ];
+  const P_LOW: f64 = 0.02425;
+
+  let tail = |q: f64| {
+    (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+      / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+  };
+
+  if p < P_LOW {
+    tail((-2.0 * p.ln()).sqrt())
+  } else if p > 1.0 - P_LOW {
+    -tail((-2.0 * (1.0 - p).ln()).sqrt())
+  } else {
+    let q = p - 0.5;
+    let r = q * q;
+    (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+      / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+  }
+}
+
+impl<T> FromIterator<Commit<T>> for Stats
+{
+  fn from_iter<I: IntoIterator<Item = Commit<T>>>(iter: I) -> Self
+  {
+    let mut stats = Stats::default();
+    for commit in iter.into_iter().filter(|x| x.alternative().is_none()) {
+      stats.record(commit.actual(), commit.guess(), commit.confidence());
+    }
+    stats
+  }
+}
+
+impl Stats
+{
+  /// Adds a single commit.
+  pub fn record(&mut self, actual: &SeenUnseen, guess: &SeenUnseen, confidence: Option<Confidence>)
+  {
+    match actual {
+      SeenUnseen::Seen => self.seen_ratings[rating(guess, confidence)] += 1,
+      SeenUnseen::Unseen => self.unseen_ratings[rating(guess, confidence)] += 1,
+    }
+  }
+
+  /// Adds the commits of `other`.
+  pub fn merge(&mut self, other: &Stats)
+  {
+    for i in 0..RATINGS {
+      self.seen_ratings[i] += other.seen_ratings[i];
+      self.unseen_ratings[i] += other.unseen_ratings[i];
+    }
+  }
+
+  /// Seen elements guessed as seen.
+  pub fn hits(&self) -> usize
+  {
+    self.seen_ratings[..RATINGS / 2].iter().sum()
+  }
+
+  /// Seen elements guessed as unseen.
+  pub fn misses(&self) -> usize
+  {
+    self.seen_ratings[RATINGS / 2..].iter().sum()
+  }
+
+  /// Unseen elements guessed as seen.
+  pub fn false_alarms(&self) -> usize
+  {
+    self.unseen_ratings[..RATINGS / 2].iter().sum()
+  }
+
+  /// Unseen elements guessed as unseen.
+  pub fn correct_rejections(&self) -> usize
+  {
+    self.unseen_ratings[RATINGS / 2..].iter().sum()
+  }
+
+  /// The points of the ROC curve as pairs of false alarm rate and hit rate, starting at (0, 0) and
+  /// ending at (1, 1). Each confidence level adds a point in between. Is empty if there are no
+  /// seen or no unseen commits.
+  pub fn roc(&self) -> Vec<(f64, f64)>
+  {
+    let seen: usize = self.seen_ratings.iter().sum();
+    let unseen: usize = self.unseen_ratings.iter().sum();
+    if seen == 0 || unseen == 0 {
+      return Vec::new();
+    }
+
+    (0..=RATINGS)
+      .map(|k| {
+        (
+          self.unseen_ratings[..k].iter().sum::<usize>() as f64 / unseen as f64,
+          self.seen_ratings[..k].iter().sum::<usize>() as f64 / seen as f64,
+        )
+      })
+      .collect()
+  }
+
+  /// The sensitivity index d′, the distance between the means of the seen and unseen
+  /// distributions in standard deviations. The rates are corrected by adding 0.5 to every count, so
+  /// that a perfect game does not give an infinite d′. Is `None` if there are no seen or no unseen
+  /// commits.
+  pub fn d_prime(&self) -> Option<f64>
+  {
+    let seen = self.hits() + self.misses();
+    let unseen = self.false_alarms() + self.correct_rejections();
+    (seen > 0 && unseen > 0).then(|| {
+      let hit_rate = (self.hits() as f64 + 0.5) / (seen as f64 + 1.0);
+      let false_alarm_rate = (self.false_alarms() as f64 + 0.5) / (unseen as f64 + 1.0);
+      probit(hit_rate) - probit(false_alarm_rate)
+    })
+  }
+
+  /// The area under the ROC curve. A value of 0.5 is chance and 1.0 is perfect discrimination.
+  pub fn auc(&self) -> Option<f64>
+  {
+    let roc = self.roc();
+    (!roc.is_empty()).then(|| {
+      roc
+        .windows(2)
+        .map(|w| (w[1].0 - w[0].0) * (w[1].1 + w[0].1) / 2.0)
+        .sum()
+    })
+  }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::{
+  coder::UnseenSetID,
+  game::Game,
+  game_over::GameOver,
+};
+
+fn play(seed: u64, mut commit: impl FnMut(&mut Game<[u8; 1]>, bool)) -> GameOver<[u8; 1]>
+{
+  let mut game = Game::new(
+    seed,
+    0.5.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+  );
+  while !game.finished() {
+    game.next().unwrap();
+    let seen = game.is_current_seen().unwrap();
+    commit(&mut game, seen);
+  }
+  GameOver::from(game)
+}
+
+#[test]
+fn always_guessing_seen_is_chance()
+{
+  let stats: Stats = play(5012883621424357305, |game, _| {
+    game.commit_seen().unwrap();
+  })
+  .iter()
+  .collect();
+
+  assert_eq!(stats.misses(), 0);
+  assert_eq!(stats.correct_rejections(), 0);
+  assert_eq!(stats.false_alarms(), 3);
+  assert_eq!(stats.auc(), Some(0.5));
+}
+
+#[test]
+fn confident_when_correct_is_perfect()
+{
+  let mut i = 0;
+  let stats: Stats = play(14427312087052811171, |game, seen| {
+    // Wrong but unsure every fourth commit, otherwise correct and sure.
+    i += 1;
+    let (guess, confidence) = if i % 4 == 0 {
+      (!seen, Confidence::Guess)
+    } else {
+      (seen, Confidence::Sure)
+    };
+    if guess {
+      game.commit_seen_with_confidence(confidence).unwrap();
+    } else {
+      game.commit_unseen_with_confidence(confidence).unwrap();
+    }
+  })
+  .iter()
+  .collect();
+
+  let roc = stats.roc();
+  assert_eq!(roc.first(), Some(&(0.0, 0.0)));
+  assert_eq!(roc.last(), Some(&(1.0, 1.0)));
+  assert!(stats.auc().unwrap() > 0.9);
+}
+
+#[test]
+fn empty_without_both_seen_and_unseen()
+{
+  let stats = Stats::default();
+  assert!(stats.roc().is_empty());
+  assert_eq!(stats.auc(), None);
+  assert_eq!(stats.d_prime(), None);
+}
+
+#[test]
+fn d_prime_of_rates()
+{
+  for (p, z) in [(0.5, 0.0), (0.975, 1.959964), (0.01, -2.326348)] {
+    assert!((probit(p) - z).abs() < 1e-6);
+  }
+
+  let mut stats = Stats::default();
+  for _ in 0..9 {
+    stats.record(&SeenUnseen::Seen, &SeenUnseen::Seen, None);
+    stats.record(&SeenUnseen::Unseen, &SeenUnseen::Unseen, None);
+  }
+  // Hit rate 9.5 / 10 and false alarm rate 0.5 / 10.
+  assert!((stats.d_prime().unwrap() - 2.0 * 1.644854).abs() < 1e-5);
+
+  stats.record(&SeenUnseen::Unseen, &SeenUnseen::Seen, None);
+  assert!(stats.d_prime().unwrap() < 2.0 * 1.644854);
+}
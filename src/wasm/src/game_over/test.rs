@@ -6,11 +6,14 @@ use std::{
 use crate::{
   coder::UnseenSetID,
   game::{
+    Confidence,
     Game,
+    GameMode,
     INITIAL_LIVES_AMOUNT,
   },
   game_over::{
     GameOver,
+    GhostRace,
     SeenUnseen,
   },
 };
@@ -151,7 +154,7 @@ fn correct_for_a_somewhat_realistic_game()
 
   let mut lives = INITIAL_LIVES_AMOUNT;
 
-  for (i, (commit, element)) in iter::zip(game_over.into_iter(), elements.iter()).enumerate() {
+  for (i, (commit, element)) in iter::zip(game_over, elements.iter()).enumerate() {
     assert_eq!(commit.element(), element);
     if incorrect.contains(&i) {
       assert!(!commit.correct());
@@ -192,8 +195,9 @@ fn initialized_with_new_works()
     UnseenSetID::Unspecified,
     (0..64).map(|x| [x]).collect(),
     0.5.try_into().unwrap(),
-    [Some(14), Some(22), Some(35)],
-  );
+    vec![Some(14), Some(22), Some(35)],
+  )
+  .unwrap();
   assert_eq!(game_over.score(), 33);
   assert_eq!(game_over.lives(), 0);
   assert_eq!(
@@ -201,3 +205,355 @@ fn initialized_with_new_works()
     [Some(14), Some(22), Some(35)]
   );
 }
+
+#[test]
+fn adaptive_game_is_reproduced_by_new()
+{
+  let mut game = Game::with_mode(
+    3813420364810316745,
+    0.5.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    GameMode::Adaptive,
+  );
+  let elements = run_game(&mut game, &[2, 9, 11, 14, 15, 17, 20, 21, 26, 27, 30]);
+  let game_over = GameOver::with_mode(
+    game.seed(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    0.5.try_into().unwrap(),
+    GameMode::Adaptive,
+    game.incorrect_commits(),
+  )
+  .unwrap();
+
+  assert_eq!(game_over.score(), game.score());
+  assert_eq!(game_over.element_checksum(), game.element_checksum());
+  for (commit, element) in iter::zip(game_over.iter(), elements.iter()) {
+    assert_eq!(commit.element(), element);
+  }
+}
+
+#[test]
+fn window_game_iterator_reconstructs_actual()
+{
+  use SeenUnseen::*;
+
+  let mut game = Game::with_mode(
+    14860424212040958411,
+    0.5.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..12).map(|x| [x]).collect(),
+    GameMode::Window(3),
+  );
+  let mut actual = Vec::new();
+  while !game.finished() {
+    game.next().unwrap();
+    let seen = game.is_current_seen().unwrap();
+    actual.push(if seen { Seen } else { Unseen });
+    // Always guess that the element is unseen.
+    game.commit_unseen().unwrap();
+  }
+
+  let game_over = GameOver::from(game);
+  for (commit, actual) in iter::zip(game_over.iter(), actual.iter()) {
+    assert_eq!(commit.actual(), actual);
+    assert_eq!(commit.guess(), &Unseen);
+  }
+  assert_eq!(game_over.iter().count(), actual.len());
+}
+
+#[test]
+fn n_back_game_is_reproduced_by_new()
+{
+  let mut game = Game::with_mode(
+    10863580733425862113,
+    0.3.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    GameMode::NBack(2),
+  );
+  let elements = run_game(&mut game, &[3, 5, 6, 8, 12, 13, 17, 20, 23, 24, 29]);
+  let game_over = GameOver::with_mode(
+    game.seed(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    0.3.try_into().unwrap(),
+    GameMode::NBack(2),
+    game.incorrect_commits(),
+  )
+  .unwrap();
+
+  assert_eq!(game_over.score(), game.score());
+  assert_eq!(game_over.element_checksum(), game.element_checksum());
+  for (i, (commit, element)) in iter::zip(game_over.iter(), elements.iter()).enumerate() {
+    assert_eq!(commit.element(), element);
+    assert_eq!(
+      commit.actual() == &SeenUnseen::Seen,
+      i >= 2 && elements[i - 2] == *element
+    );
+  }
+}
+
+#[test]
+fn forced_choice_game_is_reproduced_by_new()
+{
+  use SeenUnseen::*;
+
+  let mut game = Game::with_mode(
+    4290036188016374871,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    GameMode::ForcedChoice,
+  );
+  let mut pairs = Vec::new();
+  game.next().unwrap();
+  game.commit_unseen().unwrap();
+  for i in 0.. {
+    if game.finished() {
+      break;
+    }
+    let (x, y) = game.next_pair().map(|(x, y)| (*x, *y)).unwrap();
+    pairs.push((x, y));
+    game.commit_choice(i % 4 / 3).unwrap();
+  }
+
+  let game_over = GameOver::with_mode(
+    game.seed(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    0.4.try_into().unwrap(),
+    GameMode::ForcedChoice,
+    game.incorrect_commits(),
+  )
+  .unwrap();
+  assert_eq!(game_over.score(), game.score());
+  assert_eq!(game_over.element_checksum(), game.element_checksum());
+
+  let mut commits = game_over.iter();
+  assert_eq!(commits.next().unwrap().alternative(), None);
+  for (i, (commit, (x, y))) in iter::zip(commits, pairs.iter()).enumerate() {
+    assert_eq!(commit.element(), x);
+    assert_eq!(commit.alternative(), Some(y));
+    assert_eq!(commit.guess(), if i % 4 / 3 == 0 { &Seen } else { &Unseen });
+  }
+}
+
+#[test]
+fn iterator_returns_confidences()
+{
+  let mut game = Game::new(
+    10283164718469442951,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..32).map(|x| [x]).collect(),
+  );
+  let mut confidences = Vec::new();
+  while !game.finished() {
+    game.next().unwrap();
+    let confidence = Confidence::try_from(confidences.len() as u8 % 3 + 1).unwrap();
+    game.commit_unseen_with_confidence(confidence).unwrap();
+    confidences.push(Some(confidence));
+  }
+  let game_over = GameOver::from(game);
+  let replayed = GameOver::new(
+    game_over.seed(),
+    UnseenSetID::Unspecified,
+    (0..32).map(|x| [x]).collect(),
+    0.4.try_into().unwrap(),
+    game_over.incorrect_commits(),
+  )
+  .unwrap()
+  .with_confidences(confidences.clone());
+
+  for game_over in [game_over, replayed] {
+    let commits: Vec<_> = game_over.iter().map(|x| x.confidence()).collect();
+    assert_eq!(commits, confidences);
+  }
+}
+
+#[test]
+fn ghost_race_replaying_the_ghost_never_diverges()
+{
+  let unseen: Vec<[u8; 1]> = (0..64).map(|x| [x]).collect();
+  let ghost = GameOver::new(
+    1842,
+    UnseenSetID::Unspecified,
+    unseen.clone(),
+    0.5.try_into().unwrap(),
+    vec![Some(3), Some(8), Some(9)],
+  )
+  .unwrap();
+
+  let mut race = GhostRace::new(&ghost, unseen);
+  let mut i = 0;
+  while !race.game().finished() {
+    race.next_element().unwrap();
+    let seen = race.game().is_current_seen().unwrap();
+    let step = if seen == [3, 8, 9].contains(&i) {
+      race.commit_unseen().unwrap()
+    } else {
+      race.commit_seen().unwrap()
+    };
+    assert!(step.same_element);
+    assert_eq!(step.ghost_correct, Some(step.correct));
+    assert_eq!(step.lives, step.ghost_lives);
+    i += 1;
+  }
+
+  assert!(race.diff().iter().all(|x| !x.diverges()));
+  assert_eq!(race.lives_diverged_at(), None);
+}
+
+#[test]
+fn ghost_race_reports_where_lives_diverged()
+{
+  let unseen: Vec<[u8; 1]> = (0..64).map(|x| [x]).collect();
+  let ghost = GameOver::new(
+    1842,
+    UnseenSetID::Unspecified,
+    unseen.clone(),
+    0.5.try_into().unwrap(),
+    vec![Some(2), Some(4), Some(6)],
+  )
+  .unwrap();
+
+  // Plays correctly until the ghost is out of lives and then loses every commit.
+  let mut race = GhostRace::new(&ghost, unseen);
+  while !race.game().finished() {
+    race.next_element().unwrap();
+    let correct = race.commits().len() < 7;
+    let step = if race.game().is_current_seen().unwrap() == correct {
+      race.commit_seen().unwrap()
+    } else {
+      race.commit_unseen().unwrap()
+    };
+    assert_eq!(step.ghost_correct.is_none(), step.index >= 7);
+  }
+
+  let diff = race.diff();
+  assert_eq!(diff.len(), 10);
+  assert_eq!(race.lives_diverged_at(), Some(2));
+  assert_eq!(diff[6].ghost_lives, 0);
+  assert_eq!(diff[6].lives, INITIAL_LIVES_AMOUNT);
+  assert!(diff[7].diverges() && diff[7].ghost.is_none());
+}
+
+#[test]
+fn unfinished_game_only_replays_its_commits()
+{
+  let mut game = Game::new(
+    8342750112846091327,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+  );
+  let mut elements = Vec::new();
+  for _ in 0..5 {
+    elements.push(*game.next().unwrap());
+    game.commit_unseen().unwrap();
+  }
+  let game_over = GameOver::from(game);
+  let replayed: Vec<[u8; 1]> = game_over.iter().map(|x| *x.element()).collect();
+  assert_eq!(replayed, elements);
+}
+
+#[test]
+fn rows_track_lives_and_score()
+{
+  let mut game = Game::new(
+    2994118206093817257,
+    0.5.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| format!("w{}", x)).collect(),
+  );
+  run_game(&mut game, &[1, 4, 6, 9]);
+  let game_over = GameOver::from(game);
+  let rows = game_over.rows();
+
+  assert_eq!(rows.len(), game_over.commit_count());
+  let mut lives = INITIAL_LIVES_AMOUNT;
+  let mut score = 0;
+  for (i, (row, commit)) in iter::zip(&rows, game_over.iter()).enumerate() {
+    assert_eq!(row.index, i);
+    assert_eq!(&row.element, commit.element());
+    assert_eq!(row.correct, commit.correct());
+    if row.correct {
+      score += 1;
+    } else {
+      lives -= 1;
+    }
+    assert_eq!((row.lives, row.score), (lives, score));
+  }
+  assert_eq!(
+    rows.last().map(|x| (x.lives, x.score)),
+    Some((0, game_over.score()))
+  );
+}
+
+#[test]
+fn exports_have_a_line_per_commit()
+{
+  let mut game = Game::new(
+    2994118206093817257,
+    0.5.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| format!("w|\"{},x\"", x)).collect(),
+  );
+  run_game(&mut game, &[1, 4, 6, 9]);
+  let game_over = GameOver::from(game);
+  let rows = game_over.rows();
+
+  let text = game_over.to_text_table();
+  assert_eq!(text.lines().count(), rows.len() + 1);
+  assert!(text.starts_with("index  element"));
+
+  let markdown = game_over.to_markdown_table();
+  assert_eq!(markdown.lines().count(), rows.len() + 2);
+  let first = markdown.lines().nth(2).unwrap();
+  assert_eq!(
+    first,
+    format!(
+      "| 0 | {} | {} | {} | {} | {} | {} |",
+      rows[0].element.replace('|', "\\|"),
+      if rows[0].actual == SeenUnseen::Seen {
+        "seen"
+      } else {
+        "unseen"
+      },
+      if rows[0].guess == SeenUnseen::Seen {
+        "seen"
+      } else {
+        "unseen"
+      },
+      if rows[0].correct { "✓" } else { "✗" },
+      rows[0].lives,
+      rows[0].score
+    )
+  );
+
+  let csv = game_over.to_csv();
+  assert_eq!(csv.lines().count(), rows.len() + 1);
+  for (line, row) in csv.lines().skip(1).zip(&rows) {
+    let element = format!("\"{}\"", row.element.replace('"', "\"\""));
+    assert!(line.starts_with(&format!("{},{},,", row.index, element)));
+    assert!(line.ends_with(&format!(",{},{},{}", row.correct, row.lives, row.score)));
+  }
+
+  let json: Vec<serde_json::Value> = serde_json::from_str(&game_over.to_json().unwrap()).unwrap();
+  assert_eq!(json.len(), rows.len());
+  assert_eq!(json[0]["element"], rows[0].element.as_str());
+  assert_eq!(json[0]["alternative"], serde_json::Value::Null);
+  assert_eq!(json[0]["lives"], rows[0].lives);
+
+  // The JSON has the confidence and reaction time of each commit.
+  let game_over = game_over
+    .with_confidences(vec![Some(Confidence::Sure)])
+    .with_reaction_times(vec![Some(420)]);
+  let json: Vec<serde_json::Value> = serde_json::from_str(&game_over.to_json().unwrap()).unwrap();
+  assert_eq!(json[0]["confidence"], "Sure");
+  assert_eq!(json[0]["reaction_time"], 420);
+  assert_eq!(json[1]["confidence"], serde_json::Value::Null);
+  assert_eq!(json[1]["reaction_time"], serde_json::Value::Null);
+}
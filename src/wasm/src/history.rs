@@ -0,0 +1,11 @@
+mod file_store;
+mod history_store;
+mod memory_store;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use file_store::FileStore;
+pub use history_store::{
+  HistoryStore,
+  Record,
+};
+pub use memory_store::MemoryStore;
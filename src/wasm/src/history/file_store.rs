@@ -0,0 +1,61 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+#[cfg(test)]
+mod test;
+
+use std::{
+  error::Error,
+  fs::{
+    self,
+    File,
+    OpenOptions,
+  },
+  io::Write,
+  path::PathBuf,
+};
+
+use crate::history::{
+  HistoryStore,
+  Record,
+};
+
+/// A `HistoryStore` backed by a JSON-lines file, in the same format as `HistoryStore::export`.
+#[derive(Clone, Debug)]
+pub struct FileStore(PathBuf);
+
+impl FileStore
+{
+  /// A store backed by the file at `path`. The file is created when the first record is pushed.
+  pub fn new(path: impl Into<PathBuf>) -> FileStore
+  {
+    FileStore(path.into())
+  }
+}
+
+impl HistoryStore for FileStore
+{
+  fn push(&mut self, record: Record) -> Result<(), Box<dyn Error>>
+  {
+    let mut file = OpenOptions::new().create(true).append(true).open(&self.0)?;
+    writeln!(file, "{}", serde_json::to_string(&record)?)?;
+    Ok(())
+  }
+
+  fn records(&self) -> Result<Vec<Record>, Box<dyn Error>>
+  {
+    if !self.0.exists() {
+      return Ok(Vec::new());
+    }
+    fs::read_to_string(&self.0)?
+      .lines()
+      .filter(|x| !x.trim().is_empty())
+      .map(|x| Ok(serde_json::from_str(x)?))
+      .collect()
+  }
+
+  fn clear(&mut self) -> Result<(), Box<dyn Error>>
+  {
+    File::create(&self.0)?;
+    Ok(())
+  }
+}
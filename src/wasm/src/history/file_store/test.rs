@@ -0,0 +1,35 @@
+use super::*;
+use crate::{
+  coder::{
+    GameOverCoderV02,
+    SealedEncodedGameOver,
+    UnseenSetID,
+  },
+  game_over::GameOver,
+};
+
+#[test]
+fn push_and_read_back()
+{
+  let path = std::env::temp_dir().join(format!("deja-vu-history-{}.jsonl", std::process::id()));
+  let mut store = FileStore::new(&path);
+  store.clear().unwrap();
+
+  let game_over = GameOver::new(
+    3,
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    0.4.try_into().unwrap(),
+    vec![Some(1), Some(2), Some(3)],
+  )
+  .unwrap();
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap();
+  store.push(Record::new(1, sealed.clone())).unwrap();
+  store.push(Record::new(2, sealed)).unwrap();
+
+  let records = FileStore::new(&path).records().unwrap();
+  assert_eq!(records.len(), 2);
+  assert_eq!(records[1].timestamp(), 2);
+
+  fs::remove_file(path).unwrap();
+}
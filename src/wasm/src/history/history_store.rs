@@ -0,0 +1,105 @@
+use std::error::Error;
+
+use serde::{
+  Deserialize,
+  Serialize,
+};
+
+use crate::coder::{
+  EncodedGameOver,
+  SealedEncodedGameOver,
+};
+
+// -------------------------------------------------------------------------------------------------
+// Record
+// -------------------------------------------------------------------------------------------------
+
+/// A played game and when it was played. Serialized as a `SealedEncodedGameOver` with an extra
+/// `timestamp` field, so that plain `SealedEncodedGameOver` records can be imported as well.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Record
+{
+  /// Milliseconds since the unix epoch, as given by `Date.now()`.
+  #[serde(default)]
+  timestamp: u64,
+
+  #[serde(flatten)]
+  game: SealedEncodedGameOver,
+}
+
+impl Record
+{
+  pub fn new(timestamp: u64, game: SealedEncodedGameOver) -> Record
+  {
+    Record { timestamp, game }
+  }
+
+  pub fn timestamp(&self) -> u64
+  {
+    self.timestamp
+  }
+
+  pub fn game(&self) -> &SealedEncodedGameOver
+  {
+    &self.game
+  }
+
+  /// True if both records holds the same encoded game.
+  fn same_game(&self, other: &Record) -> bool
+  {
+    self.game.checksum() == other.game.checksum()
+      && serde_json::to_value(&self.game).ok() == serde_json::to_value(&other.game).ok()
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// HistoryStore
+// -------------------------------------------------------------------------------------------------
+
+/// Storage of the games played by a player.
+pub trait HistoryStore
+{
+  /// Adds a record.
+  fn push(&mut self, record: Record) -> Result<(), Box<dyn Error>>;
+
+  /// Every record in the order they were added.
+  fn records(&self) -> Result<Vec<Record>, Box<dyn Error>>;
+
+  /// Removes every record.
+  fn clear(&mut self) -> Result<(), Box<dyn Error>>;
+
+  /// The records as JSON-lines.
+  fn export(&self) -> Result<String, Box<dyn Error>>
+  {
+    let mut s = String::new();
+    for record in self.records()? {
+      s += &serde_json::to_string(&record)?;
+      s.push('\n');
+    }
+    Ok(s)
+  }
+
+  /// Adds the records of JSON-lines exported with `HistoryStore::export`. Records with an invalid
+  /// checksum fails the import before anything is added and games that are already stored are
+  /// skipped. Returns the amount of added records.
+  fn import(&mut self, jsonl: &str) -> Result<usize, Box<dyn Error>>
+  {
+    let mut records = self.records()?;
+    let existing = records.len();
+
+    for line in jsonl.lines().filter(|x| !x.trim().is_empty()) {
+      let record: Record = serde_json::from_str(line)?;
+      EncodedGameOver::try_from(record.game.clone())?;
+      if !records.iter().any(|x| x.same_game(&record)) {
+        records.push(record);
+      }
+    }
+
+    let added = records.split_off(existing);
+    let count = added.len();
+    for record in added {
+      self.push(record)?;
+    }
+    Ok(count)
+  }
+}
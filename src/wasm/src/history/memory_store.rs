@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod test;
+
+use std::error::Error;
+
+use crate::history::{
+  HistoryStore,
+  Record,
+};
+
+/// A `HistoryStore` that only lives as long as the value.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStore(Vec<Record>);
+
+impl HistoryStore for MemoryStore
+{
+  fn push(&mut self, record: Record) -> Result<(), Box<dyn Error>>
+  {
+    self.0.push(record);
+    Ok(())
+  }
+
+  fn records(&self) -> Result<Vec<Record>, Box<dyn Error>>
+  {
+    Ok(self.0.clone())
+  }
+
+  fn clear(&mut self) -> Result<(), Box<dyn Error>>
+  {
+    self.0.clear();
+    Ok(())
+  }
+}
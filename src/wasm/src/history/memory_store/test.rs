@@ -0,0 +1,69 @@
+use super::*;
+use crate::{
+  coder::{
+    GameOverCoderV02,
+    SealedEncodedGameOver,
+    UnseenSetID,
+  },
+  game_over::GameOver,
+};
+
+fn record(seed: u64, timestamp: u64) -> Record
+{
+  let game_over = GameOver::new(
+    seed,
+    UnseenSetID::Unspecified,
+    (0..64).map(|x| [x]).collect(),
+    0.4.try_into().unwrap(),
+    vec![Some(1), Some(2), Some(3)],
+  )
+  .unwrap();
+  Record::new(
+    timestamp,
+    SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap(),
+  )
+}
+
+#[test]
+fn export_import()
+{
+  let mut store = MemoryStore::default();
+  store.push(record(1, 100)).unwrap();
+  store.push(record(2, 200)).unwrap();
+  let exported = store.export().unwrap();
+  assert_eq!(exported.lines().count(), 2);
+
+  let mut other = MemoryStore::default();
+  other.push(record(2, 300)).unwrap();
+  assert_eq!(other.import(&exported).unwrap(), 1);
+
+  let records = other.records().unwrap();
+  assert_eq!(
+    records.iter().map(|x| x.timestamp()).collect::<Vec<_>>(),
+    [300, 100]
+  );
+
+  other.clear().unwrap();
+  assert!(other.records().unwrap().is_empty());
+}
+
+#[test]
+fn import_plain_sealed()
+{
+  let sealed = serde_json::to_string(record(1, 100).game()).unwrap();
+  let mut store = MemoryStore::default();
+  assert_eq!(store.import(&sealed).unwrap(), 1);
+  assert_eq!(store.records().unwrap()[0].timestamp(), 0);
+}
+
+#[test]
+fn import_fails_on_bad_checksum()
+{
+  let mut store = MemoryStore::default();
+  let line = serde_json::to_string(&record(1, 100))
+    .unwrap()
+    .replace("\"checksum\":", "\"checksum\":1");
+  let jsonl = serde_json::to_string(&record(2, 100)).unwrap() + "\n" + &line;
+  assert!(store.import(&jsonl).is_err());
+  assert!(store.records().unwrap().is_empty());
+}
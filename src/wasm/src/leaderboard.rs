@@ -0,0 +1,430 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+  collections::HashMap,
+  error::Error,
+  fmt::Display,
+  str::FromStr,
+};
+
+use serde::{
+  Deserialize,
+  Serialize,
+};
+
+use crate::{
+  coder::{
+    Authenticity,
+    ChallengeError,
+    EncodedGameOver,
+    MacKey,
+    SealedChallenge,
+    SealedEncodedGameOver,
+    UnseenSetID,
+  },
+  game::{
+    GameMode,
+    INITIAL_LIVES_AMOUNT,
+  },
+  game_over::GameOver,
+  plausibility::{
+    Plausibility,
+    Verdict,
+  },
+};
+
+const SECONDS_PER_DAY: u64 = 86400;
+
+// -------------------------------------------------------------------------------------------------
+// Leaderboard
+// -------------------------------------------------------------------------------------------------
+
+/// Scores of submitted games. A game is only accepted if it can be verified by replaying it against
+/// the word list of its `UnseenSetID`.
+///
+/// The checksums of a share code are not keyed, so its settings are only trusted if they are the
+/// defaults. A game started with another amount of lives is only accepted as the result of a
+/// challenge added with `Leaderboard::with_challenge`.
+pub struct Leaderboard
+{
+  words: HashMap<UnseenSetID, Vec<String>>,
+  challenges: HashMap<u64, SealedChallenge>,
+  entries: Vec<Entry>,
+  // The game of each entry, in the same order.
+  played: Vec<PlayedGame>,
+  key: Option<MacKey>,
+  plausibility: Option<Plausibility>,
+}
+
+impl Leaderboard
+{
+  /// A leaderboard accepting games played with the given word lists.
+  pub fn new(words: HashMap<UnseenSetID, Vec<String>>) -> Leaderboard
+  {
+    Leaderboard {
+      words,
+      challenges: HashMap::new(),
+      entries: Vec::new(),
+      played: Vec::new(),
+      key: None,
+      plausibility: None,
+    }
+  }
+
+  /// Only accepts games signed with `key`.
+  pub fn with_key(mut self, key: MacKey) -> Leaderboard
+  {
+    self.key = Some(key);
+    self
+  }
+
+  /// Accepts results of `challenge`, which must be played with its settings.
+  pub fn with_challenge(mut self, challenge: SealedChallenge) -> Leaderboard
+  {
+    self.challenges.insert(challenge.id(), challenge);
+    self
+  }
+
+  /// Only accepts games that `plausibility` finds plausible, which requires enough reaction times
+  /// to check, see `Verdict::plausible_strict`.
+  pub fn with_plausibility(mut self, plausibility: Plausibility) -> Leaderboard
+  {
+    self.plausibility = Some(plausibility);
+    self
+  }
+
+  /// Verifies `sealed` and adds it as an entry submitted at `timestamp`, in seconds since the unix
+  /// epoch.
+  ///
+  /// A game is a duplicate if the same game has already been submitted, even if its share code was
+  /// sealed with another coder. Distinct games may share a seed, e.g. the results of a challenge.
+  pub fn submit(
+    &mut self,
+    name: String,
+    sealed: SealedEncodedGameOver,
+    timestamp: u64,
+  ) -> Result<&Entry, LeaderboardError>
+  {
+    let game_over = self.verify(&sealed)?;
+    if let Some(plausibility) = &self.plausibility {
+      let verdict = plausibility.check(&game_over);
+      if !verdict.plausible() {
+        return Err(LeaderboardError::Implausible(verdict));
+      }
+      if !verdict.plausible_strict() {
+        return Err(LeaderboardError::Untimed);
+      }
+    }
+    self.add(name, sealed, game_over, timestamp)
+  }
+
+  /// Adds an entry that was previously returned by `submit`, verifying it again. The plausibility
+  /// is not checked again, so that changing its thresholds keeps earlier entries.
+  pub fn restore(&mut self, entry: Entry) -> Result<(), LeaderboardError>
+  {
+    let game_over = self.verify(&entry.sealed)?;
+    self
+      .add(entry.name, entry.sealed, game_over, entry.timestamp)
+      .map(|_| ())
+  }
+
+  fn add(
+    &mut self,
+    name: String,
+    sealed: SealedEncodedGameOver,
+    game_over: GameOver<String>,
+    timestamp: u64,
+  ) -> Result<&Entry, LeaderboardError>
+  {
+    let played = PlayedGame::new(&game_over);
+    if self.played.contains(&played) {
+      return Err(LeaderboardError::Duplicate);
+    }
+    self.played.push(played);
+
+    self.entries.push(Entry {
+      name,
+      score: game_over.score(),
+      seed: game_over.seed(),
+      mode: game_over.mode().clone(),
+      lives: game_over.initial_lives(),
+      timestamp,
+      sealed,
+    });
+    Ok(self.entries.last().unwrap())
+  }
+
+  /// The entries in the order they were submitted.
+  pub fn entries(&self) -> &[Entry]
+  {
+    &self.entries
+  }
+
+  /// The entries of games played with `mode` and `lives` ordered by score, optionally limited to
+  /// an `UnseenSetID` and a day. Entries with the same score are ordered by submission.
+  pub fn ranking(
+    &self,
+    mode: &GameMode,
+    lives: usize,
+    unseen_set_id: Option<&UnseenSetID>,
+    day: Option<Day>,
+  ) -> Vec<&Entry>
+  {
+    let mut ranking: Vec<&Entry> = self
+      .entries
+      .iter()
+      .filter(|x| &x.mode == mode && x.lives == lives)
+      .filter(|x| unseen_set_id.is_none_or(|id| x.unseen_set_id() == id))
+      .filter(|x| day.is_none_or(|day| x.day() == day))
+      .collect();
+    ranking.sort_by(|a, b| b.score.cmp(&a.score).then(a.timestamp.cmp(&b.timestamp)));
+    ranking
+  }
+
+  fn verify(&self, sealed: &SealedEncodedGameOver) -> Result<GameOver<String>, LeaderboardError>
+  {
+    let words = self
+      .words
+      .get(sealed.unseen_set_id())
+      .ok_or_else(|| LeaderboardError::UnknownUnseenSet(sealed.unseen_set_id().clone()))?;
+
+    let encoded = match &self.key {
+      Some(key) => EncodedGameOver::try_from((sealed.clone(), key)),
+      None => EncodedGameOver::try_from(sealed.clone()),
+    }
+    .map_err(|e| LeaderboardError::Tampered(e.to_string()))?;
+    if self.key.is_some() && encoded.authenticity() != Authenticity::Verified {
+      return Err(LeaderboardError::Unverified);
+    }
+    let game_over = GameOver::try_from((sealed.clone(), words.clone()))
+      .map_err(|e| LeaderboardError::Tampered(e.to_string()))?;
+
+    match sealed.challenge().and_then(|id| self.challenges.get(&id)) {
+      Some(challenge) => {
+        let challenge = challenge
+          .open()
+          .map_err(|e| LeaderboardError::Tampered(e.to_string()))?;
+        if !challenge.matches(&game_over) {
+          return Err(LeaderboardError::Tampered(
+            ChallengeError::SettingsMismatch.to_string(),
+          ));
+        }
+      }
+      None if game_over.initial_lives() != INITIAL_LIVES_AMOUNT => {
+        return Err(LeaderboardError::NonStandardLives(
+          game_over.initial_lives(),
+        ));
+      }
+      None => (),
+    }
+    Ok(game_over)
+  }
+}
+
+/// What makes two share codes the same game, whatever coder they were sealed with. The guesses
+/// are used rather than the commit checksum since not every coder keeps the reaction times.
+#[derive(PartialEq)]
+struct PlayedGame
+{
+  seed: u64,
+  unseen_set_id: UnseenSetID,
+  mode: GameMode,
+  lives: usize,
+  guesses: Vec<bool>,
+}
+
+impl PlayedGame
+{
+  fn new(game_over: &GameOver<String>) -> PlayedGame
+  {
+    PlayedGame {
+      seed: game_over.seed(),
+      unseen_set_id: game_over.unseen_set_id().clone(),
+      mode: game_over.mode().clone(),
+      lives: game_over.initial_lives(),
+      guesses: game_over.guesses().to_vec(),
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Entry
+// -------------------------------------------------------------------------------------------------
+
+/// A verified game on a `Leaderboard`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Entry
+{
+  name: String,
+  score: usize,
+  seed: u64,
+  mode: GameMode,
+  // Entries stored before the amount of lives was recorded are restored with it recomputed.
+  #[serde(default)]
+  lives: usize,
+  timestamp: u64,
+  sealed: SealedEncodedGameOver,
+}
+
+impl Entry
+{
+  /// The name of the player.
+  pub fn name(&self) -> &str
+  {
+    &self.name
+  }
+
+  /// The score of the game.
+  pub fn score(&self) -> usize
+  {
+    self.score
+  }
+
+  /// The `GameMode` the game was played with.
+  pub fn mode(&self) -> &GameMode
+  {
+    &self.mode
+  }
+
+  /// The amount of lives the game started with.
+  pub fn lives(&self) -> usize
+  {
+    self.lives
+  }
+
+  /// The `UnseenSetID` of the game.
+  pub fn unseen_set_id(&self) -> &UnseenSetID
+  {
+    self.sealed.unseen_set_id()
+  }
+
+  /// The time of submission in seconds since the unix epoch.
+  pub fn timestamp(&self) -> u64
+  {
+    self.timestamp
+  }
+
+  /// The day of submission.
+  pub fn day(&self) -> Day
+  {
+    Day::from_timestamp(self.timestamp)
+  }
+
+  /// The submitted game.
+  pub fn sealed(&self) -> &SealedEncodedGameOver
+  {
+    &self.sealed
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Day
+// -------------------------------------------------------------------------------------------------
+
+/// A day in UTC, written as `YYYY-MM-DD`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Day(u64);
+
+impl Day
+{
+  /// The day of `timestamp` in seconds since the unix epoch.
+  pub fn from_timestamp(timestamp: u64) -> Day
+  {
+    Day(timestamp / SECONDS_PER_DAY)
+  }
+}
+
+// The conversions between days and dates are from Howard Hinnant's `chrono`-compatible
+// algorithms, restricted to dates after 1970-01-01.
+
+impl Display for Day
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    let z = self.0 + 719468;
+    let era = z / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + (m <= 2) as u64;
+    write!(f, "{:04}-{:02}-{:02}", y, m, d)
+  }
+}
+
+impl FromStr for Day
+{
+  type Err = LeaderboardError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err>
+  {
+    let invalid = || LeaderboardError::InvalidDay(s.into());
+
+    let mut parts = s.splitn(3, '-').map(|x| x.parse::<u64>());
+    let (y, m, d) = match (parts.next(), parts.next(), parts.next()) {
+      (Some(Ok(y)), Some(Ok(m)), Some(Ok(d))) => (y, m, d),
+      _ => return Err(invalid()),
+    };
+    if y < 1970 || !(1..=12).contains(&m) || !(1..=31).contains(&d) {
+      return Err(invalid());
+    }
+
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * if m > 2 { m - 3 } else { m + 9 } + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let day = Day(era * 146097 + doe - 719468);
+
+    // Rejects days that does not exist in the month, such as 2023-02-30.
+    (day.to_string() == s).then_some(day).ok_or_else(invalid)
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Error
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub enum LeaderboardError
+{
+  Duplicate,
+  Tampered(String),
+  Unverified,
+  Implausible(Verdict),
+  Untimed,
+  NonStandardLives(usize),
+  UnknownUnseenSet(UnseenSetID),
+  InvalidDay(String),
+}
+
+impl Display for LeaderboardError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use LeaderboardError::*;
+
+    match self {
+      Duplicate => write!(f, "the game has already been submitted"),
+      Tampered(e) => write!(f, "the game could not be verified: {}", e.trim_end()),
+      Unverified => write!(f, "the game is not signed"),
+      Implausible(verdict) => write!(f, "the game is {}", verdict),
+      Untimed => write!(
+        f,
+        "the game has too few reaction times to check that it is plausible"
+      ),
+      NonStandardLives(lives) => write!(
+        f,
+        "the game started with {} lives and is not the result of a known challenge",
+        lives
+      ),
+      UnknownUnseenSet(id) => write!(f, "there is no word list for {:?}", id),
+      InvalidDay(s) => write!(f, "day '{}' is not on the form YYYY-MM-DD", s),
+    }
+  }
+}
+
+impl Error for LeaderboardError {}
@@ -0,0 +1,320 @@
+use super::*;
+use crate::coder::{
+  ChallengeBuilder,
+  CoderChecksum,
+  CoderVersion,
+  GameOverCoderV01,
+  GameOverCoderV02,
+  GameOverCoderV03,
+  LatestCoder,
+};
+
+fn words() -> Vec<String>
+{
+  (0..512).map(|i| format!("w{}", i)).collect()
+}
+
+fn leaderboard() -> Leaderboard
+{
+  Leaderboard::new(HashMap::from([(UnseenSetID::Unspecified, words())]))
+}
+
+fn sealed(seed: u64, incorrect_commits: [usize; 3]) -> SealedEncodedGameOver
+{
+  let game_over = GameOver::new(
+    seed,
+    UnseenSetID::Unspecified,
+    words(),
+    0.4.try_into().unwrap(),
+    incorrect_commits.map(Some).to_vec(),
+  )
+  .unwrap();
+  SealedEncodedGameOver::new::<GameOverCoderV02, _>(&game_over).unwrap()
+}
+
+/// A share code of `C` with `payload` as data and a valid checksum, as anyone can make.
+fn crafted<C: CoderChecksum + CoderVersion>(payload: &str) -> SealedEncodedGameOver
+{
+  let data = base64::encode(payload);
+  serde_json::from_value(serde_json::json!({
+    "version": C::version(),
+    "checksum": C::checksum(data.as_bytes()),
+    "data": data,
+    "unseen_set_id": UnseenSetID::Unspecified,
+  }))
+  .unwrap()
+}
+
+#[test]
+fn submit_and_rank()
+{
+  let mut leaderboard = leaderboard();
+  leaderboard
+    .submit("a".into(), sealed(1, [1, 2, 3]), 0)
+    .unwrap();
+  assert_eq!(
+    leaderboard
+      .submit("b".into(), sealed(2, [4, 8, 12]), SECONDS_PER_DAY)
+      .unwrap()
+      .score(),
+    10
+  );
+  leaderboard
+    .submit("c".into(), sealed(3, [4, 8, 12]), SECONDS_PER_DAY + 1)
+    .unwrap();
+
+  let names = |ranking: Vec<&Entry>| {
+    ranking
+      .iter()
+      .map(|x| x.name().to_string())
+      .collect::<Vec<_>>()
+  };
+  let ranking = |unseen_set_id, day| {
+    names(leaderboard.ranking(&GameMode::Classic, INITIAL_LIVES_AMOUNT, unseen_set_id, day))
+  };
+  assert_eq!(ranking(None, None), ["b", "c", "a"]);
+  assert_eq!(ranking(None, Some(Day::from_timestamp(0))), ["a"]);
+  assert!(ranking(Some(&UnseenSetID::Top999WiktionaryFr), None).is_empty());
+}
+
+#[test]
+fn reject_duplicate()
+{
+  let mut leaderboard = leaderboard();
+  leaderboard
+    .submit("a".into(), sealed(1, [1, 2, 3]), 0)
+    .unwrap();
+  assert_eq!(
+    leaderboard
+      .submit("b".into(), sealed(1, [1, 2, 3]), 1)
+      .unwrap_err(),
+    LeaderboardError::Duplicate
+  );
+
+  // Nor is the same game sealed with another coder or with its payload written differently.
+  let game_over = GameOver::try_from((sealed(1, [1, 2, 3]), words())).unwrap();
+  let payload: serde_json::Value =
+    serde_json::from_slice(&base64::decode(sealed(1, [1, 2, 3]).data()).unwrap()).unwrap();
+  let resealed = [
+    SealedEncodedGameOver::new::<GameOverCoderV01, _>(&game_over).unwrap(),
+    SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over).unwrap(),
+    SealedEncodedGameOver::new::<LatestCoder, _>(&game_over).unwrap(),
+    crafted::<GameOverCoderV02>(&serde_json::to_string_pretty(&payload).unwrap()),
+  ];
+  for sealed in resealed {
+    assert_eq!(
+      leaderboard.submit("b".into(), sealed, 1).unwrap_err(),
+      LeaderboardError::Duplicate
+    );
+  }
+
+  // Another game with the same seed is not a duplicate.
+  leaderboard
+    .submit("b".into(), sealed(1, [5, 6, 7]), 1)
+    .unwrap();
+  assert_eq!(leaderboard.entries().len(), 2);
+}
+
+#[test]
+fn reject_forged_lives_unless_from_a_known_challenge()
+{
+  let challenge = ChallengeBuilder::default()
+    .seed(7)
+    .seen_threshold(0.4.try_into().unwrap())
+    .unseen_set_id(UnseenSetID::Unspecified)
+    .lives(5)
+    .build()
+    .unwrap();
+  let play = |lives| {
+    let mut game = challenge.game(words()).with_lives(lives);
+    let mut i = 0;
+    while !game.finished() {
+      game.next().unwrap();
+      let seen = game.is_current_seen().unwrap();
+      i += 1;
+      game.commit_with(seen ^ (i % 4 == 0), None).unwrap();
+    }
+    GameOver::from(game)
+  };
+  let sealed_challenge = SealedChallenge::new(&challenge).unwrap();
+  let seal = |game_over: &GameOver<String>| {
+    sealed_challenge.sign(SealedEncodedGameOver::new::<LatestCoder, _>(game_over).unwrap())
+  };
+
+  let forged = SealedEncodedGameOver::new::<LatestCoder, _>(&play(40)).unwrap();
+  assert_eq!(
+    leaderboard().submit("a".into(), forged, 0).unwrap_err(),
+    LeaderboardError::NonStandardLives(40)
+  );
+  assert_eq!(
+    leaderboard()
+      .submit("a".into(), seal(&play(5)), 0)
+      .unwrap_err(),
+    LeaderboardError::NonStandardLives(5)
+  );
+
+  let mut leaderboard = leaderboard().with_challenge(sealed_challenge.clone());
+  assert!(matches!(
+    leaderboard.submit("a".into(), seal(&play(40)), 0),
+    Err(LeaderboardError::Tampered(_))
+  ));
+  leaderboard.submit("a".into(), seal(&play(5)), 0).unwrap();
+  leaderboard
+    .submit("b".into(), sealed(1, [1, 2, 3]), 0)
+    .unwrap();
+
+  // Games are only ranked against games with the same mode and lives.
+  let ranking = |lives| {
+    leaderboard
+      .ranking(&GameMode::Classic, lives, None, None)
+      .iter()
+      .map(|x| x.name().to_string())
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(ranking(5), ["a"]);
+  assert_eq!(ranking(INITIAL_LIVES_AMOUNT), ["b"]);
+}
+
+#[test]
+fn reject_games_that_can_not_be_replayed()
+{
+  // Without incorrect commits the game never ends and runs out of unseen words.
+  for incorrect_commits in [serde_json::json!([null, null, null]), serde_json::json!([])] {
+    let payload = serde_json::json!({
+      "seed": 1,
+      "seen_threshold": 400000000,
+      "incorrect_commits": incorrect_commits,
+      "element_checksum": 0,
+    });
+    let sealed = crafted::<GameOverCoderV01>(&payload.to_string());
+    assert!(matches!(
+      leaderboard().submit("a".into(), sealed, 0),
+      Err(LeaderboardError::Tampered(_))
+    ));
+  }
+}
+
+#[test]
+fn reject_tampered()
+{
+  let mut leaderboard = leaderboard();
+  let query = serde_urlencoded::to_string(sealed(1, [1, 2, 3])).unwrap();
+  let checksum = format!("checksum={}", sealed(1, [1, 2, 3]).checksum());
+  let tampered = query.replace(&checksum, "checksum=1");
+
+  assert!(matches!(
+    leaderboard.submit("a".into(), tampered.parse().unwrap(), 0),
+    Err(LeaderboardError::Tampered(_))
+  ));
+  assert!(leaderboard.entries().is_empty());
+}
+
+#[test]
+fn reject_game_played_with_other_words()
+{
+  let mut leaderboard = Leaderboard::new(HashMap::from([(
+    UnseenSetID::Unspecified,
+    words().into_iter().rev().collect(),
+  )]));
+  assert!(matches!(
+    leaderboard.submit("a".into(), sealed(1, [1, 2, 3]), 0),
+    Err(LeaderboardError::Tampered(_))
+  ));
+}
+
+#[test]
+fn keyed_leaderboard_only_accepts_signed_games()
+{
+  let key = MacKey::new("competition");
+  let mut leaderboard = leaderboard().with_key(key.clone());
+
+  assert_eq!(
+    leaderboard
+      .submit("a".into(), sealed(1, [1, 2, 3]), 0)
+      .unwrap_err(),
+    LeaderboardError::Unverified
+  );
+  assert!(matches!(
+    leaderboard.submit(
+      "b".into(),
+      sealed(1, [1, 2, 3]).with_mac(&MacKey::new("other")),
+      0
+    ),
+    Err(LeaderboardError::Tampered(_))
+  ));
+  leaderboard
+    .submit("c".into(), sealed(1, [1, 2, 3]).with_mac(&key), 0)
+    .unwrap();
+  assert_eq!(leaderboard.entries().len(), 1);
+}
+
+#[test]
+fn reject_implausible_but_restore_it()
+{
+  let game_over = GameOver::new(
+    1,
+    UnseenSetID::Unspecified,
+    words(),
+    0.4.try_into().unwrap(),
+    vec![Some(30), Some(31), Some(32)],
+  )
+  .unwrap();
+  let game_over = game_over.with_reaction_times(vec![Some(90); 33]);
+  let sealed = SealedEncodedGameOver::new::<GameOverCoderV03, _>(&game_over).unwrap();
+
+  let entry = leaderboard()
+    .submit("a".into(), sealed.clone(), 0)
+    .unwrap()
+    .clone();
+
+  let mut leaderboard = leaderboard().with_plausibility(Plausibility::default());
+  assert!(matches!(
+    leaderboard.submit("a".into(), sealed.clone(), 0),
+    Err(LeaderboardError::Implausible(verdict)) if verdict.flags.len() == 2
+  ));
+  assert!(leaderboard.entries().is_empty());
+  leaderboard.restore(entry).unwrap();
+  assert_eq!(leaderboard.entries().len(), 1);
+}
+
+#[test]
+fn plausibility_requires_reaction_times()
+{
+  let game_over = GameOver::new(
+    1,
+    UnseenSetID::Unspecified,
+    words(),
+    0.4.try_into().unwrap(),
+    vec![Some(30), Some(31), Some(32)],
+  )
+  .unwrap();
+  let timed = game_over
+    .clone()
+    .with_reaction_times((0..33).map(|i| Some(600 + 37 * (i % 9))).collect());
+  let seal = |x| SealedEncodedGameOver::new::<GameOverCoderV03, _>(x).unwrap();
+
+  let mut leaderboard = leaderboard().with_plausibility(Plausibility::default());
+  assert_eq!(
+    leaderboard
+      .submit("a".into(), seal(&game_over), 0)
+      .unwrap_err(),
+    LeaderboardError::Untimed
+  );
+  leaderboard.submit("a".into(), seal(&timed), 0).unwrap();
+}
+
+#[test]
+fn day_to_and_from_string()
+{
+  for (s, timestamp) in [
+    ("1970-01-01", 0),
+    ("2000-02-29", 951782400),
+    ("2022-10-21", 1666334722),
+  ] {
+    assert_eq!(Day::from_timestamp(timestamp).to_string(), s);
+    assert_eq!(s.parse::<Day>().unwrap(), Day::from_timestamp(timestamp));
+  }
+  assert!("2023-02-30".parse::<Day>().is_err());
+  assert!("2023-1-3".parse::<Day>().is_err());
+  assert!("yesterday".parse::<Day>().is_err());
+}
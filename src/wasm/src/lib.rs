@@ -1,17 +1,30 @@
 #[macro_use]
 extern crate derive_builder;
 
-mod coder;
-mod game;
-mod game_over;
-mod rng;
+pub mod analytics;
+pub mod coder;
+pub mod game;
+pub mod game_over;
+pub mod history;
+pub mod leaderboard;
+pub mod plausibility;
+pub mod rng;
+pub mod search;
+pub mod simulation;
 mod web_api;
+pub mod words;
 
 pub use web_api::{
+  Analytics,
+  Challenge,
   Commit,
   EncodedGameOver,
   Game,
+  GameMode,
   GameOver,
+  GhostRace,
+  History,
+  HistoryRecord,
   Konadare192PxPlusPlus,
   KSINK,
 };
@@ -0,0 +1,330 @@
+#[cfg(test)]
+mod test;
+
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::{
+  game::GameMode,
+  game_over::{
+    GameOver,
+    SeenUnseen,
+  },
+};
+
+// -------------------------------------------------------------------------------------------------
+// Flag
+// -------------------------------------------------------------------------------------------------
+
+/// An implausible pattern found in a game.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "check", rename_all = "snake_case")]
+pub enum Flag
+{
+  /// Too many commits were answered faster than a human can recognize a word.
+  TooFast
+  {
+    /// The ratio of timed commits below the floor.
+    ratio: f64,
+    floor: u32,
+  },
+
+  /// The reaction times vary too little, as measured by their coefficient of variation.
+  TooUniform
+  {
+    variation: f64
+  },
+
+  /// Not a single unseen element was claimed as seen over a long run.
+  NoFalseAlarms
+  {
+    unseen_commits: usize
+  },
+
+  /// Seen elements are recognized at least as well long after they were shown as shortly after.
+  FlatLagCurve
+  {
+    /// Hit rate of the seen commits with a lag up to the median lag.
+    short: f64,
+    /// Hit rate of the seen commits with a lag above the median lag.
+    long: f64,
+    median_lag: usize,
+  },
+}
+
+impl Display for Flag
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use Flag::*;
+
+    match self {
+      TooFast { ratio, floor } => write!(
+        f,
+        "{:.0}% of the reaction times are below {} ms",
+        ratio * 100.0,
+        floor
+      ),
+      TooUniform { variation } => write!(
+        f,
+        "the reaction times have a coefficient of variation of {:.3}",
+        variation
+      ),
+      NoFalseAlarms { unseen_commits } => {
+        write!(f, "no false alarms over {} unseen commits", unseen_commits)
+      }
+      FlatLagCurve {
+        short,
+        long,
+        median_lag,
+      } => write!(
+        f,
+        "hit rate {:.3} at lags above {} and {:.3} below",
+        long, median_lag, short
+      ),
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Verdict
+// -------------------------------------------------------------------------------------------------
+
+/// The checks a `Plausibility` runs on a game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Check
+{
+  ReactionTimeFloor,
+  ReactionTimeVariation,
+  FalseAlarms,
+  LagCurve,
+}
+
+/// The result of checking a game with `Plausibility::check`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Verdict
+{
+  /// The implausible patterns found.
+  pub flags: Vec<Flag>,
+
+  /// Checks that could not be made since the game had too few commits for them, lacked reaction
+  /// times or was played in a mode they do not apply to.
+  pub unchecked: Vec<Check>,
+}
+
+impl Verdict
+{
+  /// True if no implausible pattern was found.
+  pub fn plausible(&self) -> bool
+  {
+    self.flags.is_empty()
+  }
+
+  /// True if no implausible pattern was found and the reaction times were checked. A bot passes
+  /// the reaction time checks by leaving the times out, e.g. by sharing with a coder without them.
+  pub fn plausible_strict(&self) -> bool
+  {
+    self.plausible()
+      && !self
+        .unchecked
+        .iter()
+        .any(|x| matches!(x, Check::ReactionTimeFloor | Check::ReactionTimeVariation))
+  }
+}
+
+impl Display for Verdict
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    if self.plausible() {
+      write!(f, "plausible")?;
+    } else {
+      write!(f, "implausible: ")?;
+      for (i, flag) in self.flags.iter().enumerate() {
+        write!(f, "{}{}", if i > 0 { ", " } else { "" }, flag)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Plausibility
+// -------------------------------------------------------------------------------------------------
+
+/// Flags patterns in a `GameOver` that are unlikely for a human player. A game can be replayed from
+/// its share code alone, so a perfect game by a bot looks the same as one by a human; these checks
+/// look at what a bot rarely bothers to fake.
+#[derive(Builder, Clone, Debug)]
+pub struct Plausibility
+{
+  /// Reaction times below this many milliseconds are too fast for reading and recognizing a word.
+  #[builder(default = "200")]
+  reaction_time_floor: u32,
+
+  /// The largest ratio of timed commits that may be below `reaction_time_floor`.
+  #[builder(default = "0.1")]
+  max_fast_ratio: f64,
+
+  /// The smallest plausible coefficient of variation of the reaction times.
+  #[builder(default = "0.1")]
+  min_variation: f64,
+
+  /// The amount of timed commits needed to check the reaction times.
+  #[builder(default = "20")]
+  min_timed_commits: usize,
+
+  /// The amount of unseen commits after which a game without false alarms is flagged.
+  #[builder(default = "150")]
+  min_unseen_commits: usize,
+
+  /// The amount of seen commits needed on each side of the median lag to check the lag curve.
+  #[builder(default = "25")]
+  min_lag_commits: usize,
+
+  /// The lag curve is only flagged if the hit rate at long lags is at least this high.
+  #[builder(default = "0.95")]
+  min_long_lag_hit_rate: f64,
+}
+
+impl Default for Plausibility
+{
+  fn default() -> Self
+  {
+    PlausibilityBuilder::default().build().unwrap()
+  }
+}
+
+impl Plausibility
+{
+  /// Runs every check on `game_over`.
+  pub fn check<T>(&self, game_over: &GameOver<T>) -> Verdict
+  where
+    T: Clone + PartialEq + AsRef<[u8]>,
+  {
+    let mut verdict = Verdict {
+      flags: Vec::new(),
+      unchecked: Vec::new(),
+    };
+    let mut run = |check: Check, result: Option<Option<Flag>>| match result {
+      Some(flag) => verdict.flags.extend(flag),
+      None => verdict.unchecked.push(check),
+    };
+
+    let times: Vec<f64> = game_over
+      .reaction_times()
+      .iter()
+      .flatten()
+      .map(|&x| x as f64)
+      .collect();
+    run(Check::ReactionTimeFloor, self.check_floor(&times));
+    run(Check::ReactionTimeVariation, self.check_variation(&times));
+
+    // Pairs have no false alarms and their lag is the one of the seen element, so only modes with
+    // single elements are checked.
+    let single = game_over.mode() != &GameMode::ForcedChoice;
+    run(
+      Check::FalseAlarms,
+      single.then(|| self.check_false_alarms(game_over)).flatten(),
+    );
+    run(
+      Check::LagCurve,
+      (single && !matches!(game_over.mode(), GameMode::NBack(_)))
+        .then(|| self.check_lag_curve(game_over))
+        .flatten(),
+    );
+
+    verdict
+  }
+
+  // Each check returns `None` if it could not be made and otherwise the flag, if any.
+
+  fn check_floor(&self, times: &[f64]) -> Option<Option<Flag>>
+  {
+    if times.len() < self.min_timed_commits {
+      return None;
+    }
+    let floor = self.reaction_time_floor as f64;
+    let ratio = times.iter().filter(|&&x| x < floor).count() as f64 / times.len() as f64;
+    Some((ratio > self.max_fast_ratio).then_some(Flag::TooFast {
+      ratio,
+      floor: self.reaction_time_floor,
+    }))
+  }
+
+  fn check_variation(&self, times: &[f64]) -> Option<Option<Flag>>
+  {
+    if times.len() < self.min_timed_commits {
+      return None;
+    }
+    let n = times.len() as f64;
+    let mean = times.iter().sum::<f64>() / n;
+    let deviation = (times.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+    let variation = if mean > 0.0 { deviation / mean } else { 0.0 };
+    Some((variation < self.min_variation).then_some(Flag::TooUniform { variation }))
+  }
+
+  fn check_false_alarms<T>(&self, game_over: &GameOver<T>) -> Option<Option<Flag>>
+  where
+    T: Clone + PartialEq + AsRef<[u8]>,
+  {
+    let stats = game_over.stats();
+    let unseen_commits = stats.false_alarms() + stats.correct_rejections();
+    if unseen_commits < self.min_unseen_commits {
+      return None;
+    }
+    Some((stats.false_alarms() == 0).then_some(Flag::NoFalseAlarms { unseen_commits }))
+  }
+
+  fn check_lag_curve<T>(&self, game_over: &GameOver<T>) -> Option<Option<Flag>>
+  where
+    T: Clone + PartialEq + AsRef<[u8]>,
+  {
+    // The lag and correctness of each seen commit.
+    let mut shown: Vec<(T, usize)> = Vec::new();
+    let mut seen: Vec<(usize, bool)> = Vec::new();
+    for (i, commit) in game_over.iter().enumerate() {
+      match shown.iter_mut().find(|(x, _)| x == commit.element()) {
+        Some((_, at)) => {
+          if commit.actual() == &SeenUnseen::Seen {
+            seen.push((i - *at, commit.correct()));
+          }
+          *at = i;
+        }
+        None => shown.push((commit.element().clone(), i)),
+      }
+    }
+
+    let mut lags: Vec<usize> = seen.iter().map(|x| x.0).collect();
+    lags.sort_unstable();
+    let median_lag = *lags.get(lags.len() / 2)?;
+    let hit_rate = |commits: Vec<bool>| {
+      (commits.len() >= self.min_lag_commits)
+        .then(|| commits.iter().filter(|&&x| x).count() as f64 / commits.len() as f64)
+    };
+    let short = hit_rate(
+      seen
+        .iter()
+        .filter(|x| x.0 <= median_lag)
+        .map(|x| x.1)
+        .collect(),
+    )?;
+    let long = hit_rate(
+      seen
+        .iter()
+        .filter(|x| x.0 > median_lag)
+        .map(|x| x.1)
+        .collect(),
+    )?;
+
+    Some(
+      (long >= short && long >= self.min_long_lag_hit_rate).then_some(Flag::FlatLagCurve {
+        short,
+        long,
+        median_lag,
+      }),
+    )
+  }
+}
@@ -0,0 +1,98 @@
+use super::*;
+use crate::{
+  coder::UnseenSetID,
+  game::Game,
+  rng::Konadare192PxPlusPlus,
+};
+
+fn unseen() -> Vec<String>
+{
+  (0..2000).map(|i| format!("w{}", i)).collect()
+}
+
+/// Plays until `commits` commits or game over. `forget` gives the probability of missing a seen
+/// element given its lag and `time` the reaction time of each commit.
+fn play<F, G>(seed: u64, commits: usize, forget: F, mut time: G) -> GameOver<String>
+where
+  F: Fn(usize) -> f64,
+  G: FnMut(&mut Konadare192PxPlusPlus) -> u32,
+{
+  let mut rng = Konadare192PxPlusPlus::from_seed(seed ^ 0x5bd1e995);
+  let mut game = Game::new(
+    seed,
+    0.4.try_into().unwrap(),
+    UnseenSetID::Unspecified,
+    unseen(),
+  )
+  .with_lives(1000);
+  let mut last_shown: Vec<(String, usize)> = Vec::new();
+  for i in 0..commits {
+    let x = game.next().unwrap().clone();
+    let seen = game.is_current_seen().unwrap();
+    let lag = last_shown
+      .iter()
+      .find(|(y, _)| y == &x)
+      .map_or(0, |(_, at)| i - at);
+    let p = rng.next_with_upper_bound(1_000_000) as f64 / 1_000_000.0;
+    let guess = seen && p >= forget(lag);
+    game.set_reaction_time(time(&mut rng));
+    game.commit_with(guess, None).unwrap();
+    match last_shown.iter_mut().find(|(y, _)| y == &x) {
+      Some((_, at)) => *at = i,
+      None => last_shown.push((x, i)),
+    }
+  }
+  GameOver::from(game)
+}
+
+fn human_time(rng: &mut Konadare192PxPlusPlus) -> u32
+{
+  450 + rng.next_with_upper_bound(900)
+}
+
+#[test]
+fn bot_is_flagged_on_every_check()
+{
+  let game_over = play(1, 400, |_| 0.0, |_| 120);
+  let verdict = Plausibility::default().check(&game_over);
+  assert!(!verdict.plausible());
+  assert!(verdict.unchecked.is_empty());
+  let checks: Vec<&str> = verdict
+    .flags
+    .iter()
+    .map(|x| match x {
+      Flag::TooFast { .. } => "fast",
+      Flag::TooUniform { .. } => "uniform",
+      Flag::NoFalseAlarms { .. } => "false alarms",
+      Flag::FlatLagCurve { .. } => "lag",
+    })
+    .collect();
+  assert_eq!(checks, ["fast", "uniform", "false alarms", "lag"]);
+}
+
+#[test]
+fn forgetting_player_is_plausible()
+{
+  // The probability of missing a seen element grows with the lag, and unseen elements are never
+  // claimed as seen, so the run is kept short of the false alarm check.
+  let game_over = play(2, 200, |lag| (lag as f64 / 150.0).min(0.6), human_time);
+  let verdict = Plausibility::default().check(&game_over);
+  assert!(verdict.plausible(), "{}", verdict);
+  assert_eq!(verdict.unchecked, [Check::FalseAlarms]);
+}
+
+#[test]
+fn games_without_reaction_times_skip_the_timing_checks()
+{
+  let game_over = play(3, 30, |_| 0.0, human_time).with_reaction_times(Vec::new());
+  let verdict = Plausibility::default().check(&game_over);
+  assert_eq!(
+    verdict.unchecked,
+    [
+      Check::ReactionTimeFloor,
+      Check::ReactionTimeVariation,
+      Check::FalseAlarms,
+      Check::LagCurve
+    ]
+  );
+}
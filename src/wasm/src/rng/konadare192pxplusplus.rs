@@ -29,6 +29,7 @@ impl Konadare192PxPlusPlus
   }
 
   /// Next pseudo random number.
+  #[allow(clippy::should_implement_trait)]
   pub fn next(&mut self) -> u64
   {
     let out = self.b ^ self.c;
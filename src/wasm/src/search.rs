@@ -0,0 +1,335 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+  error::Error,
+  fmt::Display,
+  ops::Range,
+  str::FromStr,
+  thread,
+};
+
+use crate::{
+  coder::UnseenSetID,
+  game::{
+    Game,
+    GameError,
+    GameMode,
+    SeenThreshold,
+  },
+  rng::Konadare192PxPlusPlus,
+};
+
+// -------------------------------------------------------------------------------------------------
+// Criterion
+// -------------------------------------------------------------------------------------------------
+
+/// A property the first trials of a game must have to be found by a `Search`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Criterion
+{
+  /// No seen element within the given amount of trials.
+  NoSeenWithin(usize),
+
+  /// No element is longer than the given amount of characters.
+  MaxLength(usize),
+
+  /// At least the given ratio of the trials are lures.
+  MinLureDensity(f64),
+
+  /// At least the given ratio of the trials are seen.
+  MinSeenDensity(f64),
+}
+
+impl FromStr for Criterion
+{
+  type Err = SearchError;
+
+  /// Parses `no-seen-within:<n>`, `max-length:<n>`, `min-lure-density:<r>` or
+  /// `min-seen-density:<r>`.
+  fn from_str(s: &str) -> Result<Self, Self::Err>
+  {
+    let invalid = || SearchError::UnrecognisedCriterion(s.into());
+
+    let (name, value) = s.split_once(':').ok_or_else(invalid)?;
+    match name {
+      "no-seen-within" => Ok(Criterion::NoSeenWithin(
+        value.parse().map_err(|_| invalid())?,
+      )),
+      "max-length" => Ok(Criterion::MaxLength(value.parse().map_err(|_| invalid())?)),
+      "min-lure-density" => Ok(Criterion::MinLureDensity(
+        value.parse().map_err(|_| invalid())?,
+      )),
+      "min-seen-density" => Ok(Criterion::MinSeenDensity(
+        value.parse().map_err(|_| invalid())?,
+      )),
+      _ => Err(invalid()),
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Seeds
+// -------------------------------------------------------------------------------------------------
+
+/// The seeds scanned by a `Search`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Seeds
+{
+  /// Every seed in the range.
+  Range(Range<u64>),
+
+  /// The given amount of seeds, where seed `i` is the first number generated by
+  /// `Konadare192PxPlusPlus` seeded with `seed + i`.
+  Random
+  {
+    seed: u64, count: u64
+  },
+}
+
+impl Seeds
+{
+  fn len(&self) -> u64
+  {
+    match self {
+      Seeds::Range(range) => range.end.saturating_sub(range.start),
+      Seeds::Random { count, .. } => *count,
+    }
+  }
+
+  fn get(&self, i: u64) -> u64
+  {
+    match self {
+      Seeds::Range(range) => range.start + i,
+      Seeds::Random { seed, .. } => Konadare192PxPlusPlus::from_seed(seed.wrapping_add(i)).next(),
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Search
+// -------------------------------------------------------------------------------------------------
+
+/// A seed found by a `Search` and the densities of its first trials.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate
+{
+  pub seed: u64,
+  pub lure_density: f64,
+  pub seen_density: f64,
+}
+
+/// Scans seeds for games where the first trials meet every criterion.
+///
+/// The trials are the elements a player that never makes a mistake is shown, since the sequence
+/// only depends on the commits in `GameMode::Adaptive`. A lure is an unseen element that is one
+/// edit away from an element shown before it, like "mais" after "main".
+#[derive(Builder, Clone, Debug)]
+pub struct Search
+{
+  #[builder(default = "UnseenSetID::Unspecified")]
+  unseen_set_id: UnseenSetID,
+
+  #[builder(default = "0.4.try_into().unwrap()")]
+  seen_threshold: SeenThreshold,
+
+  #[builder(default)]
+  mode: GameMode,
+
+  seeds: Seeds,
+
+  #[builder(default)]
+  criteria: Vec<Criterion>,
+
+  /// Amount of trials of each game the criteria applies to. A trial is a single element or a pair.
+  #[builder(default = "20")]
+  trials: usize,
+
+  /// The most candidates returned.
+  #[builder(default = "10")]
+  limit: usize,
+
+  /// Amount of threads to scan with. Uses every core if zero.
+  #[builder(default = "0")]
+  threads: usize,
+}
+
+impl Search
+{
+  /// Scans the seeds in parallel and returns the candidates with the highest lure density, ordered
+  /// by lure density and then by seed.
+  pub fn run<T>(&self, unseen: &[T]) -> Result<Vec<Candidate>, GameError>
+  where
+    T: Clone + PartialEq + AsRef<[u8]> + Sync,
+  {
+    let threads = match self.threads {
+      0 => thread::available_parallelism().map_or(1, |x| x.get()),
+      n => n,
+    } as u64;
+
+    let results: Vec<Result<Vec<Candidate>, GameError>> = thread::scope(|scope| {
+      let handles: Vec<_> = (0..threads)
+        .map(|t| {
+          scope.spawn(move || {
+            let mut candidates = Vec::new();
+            let mut i = t;
+            while i < self.seeds.len() {
+              if let Some(candidate) = self.scan(self.seeds.get(i), unseen)? {
+                candidates.push(candidate);
+                if candidates.len() >= 2 * self.limit.max(1) {
+                  self.rank(&mut candidates);
+                }
+              }
+              i += threads;
+            }
+            Ok(candidates)
+          })
+        })
+        .collect();
+      handles.into_iter().map(|x| x.join().unwrap()).collect()
+    });
+
+    let mut candidates = Vec::new();
+    for result in results {
+      candidates.extend(result?);
+    }
+    self.rank(&mut candidates);
+    Ok(candidates)
+  }
+
+  /// Sorts `candidates` and keeps the best `limit` of them.
+  fn rank(&self, candidates: &mut Vec<Candidate>)
+  {
+    candidates.sort_by(|a, b| {
+      b.lure_density
+        .total_cmp(&a.lure_density)
+        .then(a.seed.cmp(&b.seed))
+    });
+    candidates.truncate(self.limit);
+  }
+
+  /// Plays the first trials of the game with `seed` and returns it as a candidate if it meets the
+  /// criteria.
+  fn scan<T>(&self, seed: u64, unseen: &[T]) -> Result<Option<Candidate>, GameError>
+  where
+    T: Clone + PartialEq + AsRef<[u8]>,
+  {
+    let mut game = Game::with_mode(
+      seed,
+      self.seen_threshold,
+      self.unseen_set_id.clone(),
+      unseen.to_vec(),
+      self.mode.clone(),
+    );
+
+    let mut shown: Vec<T> = Vec::new();
+    let mut seen = 0;
+    let mut lures = 0;
+    let mut longest = 0;
+
+    for trial in 0..self.trials {
+      let next = if game.expects_pair() {
+        game.next_pair().map(|(x, y)| vec![x.clone(), y.clone()])
+      } else {
+        game.next().map(|x| vec![x.clone()])
+      };
+      let elements = match next {
+        // A game that runs out of unseen elements ends there, the trials played so far are the
+        // candidate.
+        Err(GameError::UnseenEmpty) => break,
+        next => next?,
+      };
+      let actual = game.is_current_seen().unwrap();
+
+      if actual {
+        if self
+          .criteria
+          .iter()
+          .any(|x| matches!(x, Criterion::NoSeenWithin(n) if trial < *n))
+        {
+          return Ok(None);
+        }
+        seen += 1;
+      }
+      for x in &elements {
+        if !shown.contains(x) && shown.iter().any(|y| one_edit_apart(x.as_ref(), y.as_ref())) {
+          lures += 1;
+        }
+        longest = longest.max(String::from_utf8_lossy(x.as_ref()).chars().count());
+      }
+
+      game.commit_with(actual, None)?;
+      shown.extend(elements);
+    }
+
+    let trials = game.commit_count().max(1) as f64;
+    let candidate = Candidate {
+      seed,
+      lure_density: lures as f64 / trials,
+      seen_density: seen as f64 / trials,
+    };
+    let ok = self.criteria.iter().all(|x| match *x {
+      Criterion::NoSeenWithin(_) => true,
+      Criterion::MaxLength(n) => longest <= n,
+      Criterion::MinLureDensity(r) => candidate.lure_density >= r,
+      Criterion::MinSeenDensity(r) => candidate.seen_density >= r,
+    });
+    Ok(ok.then_some(candidate))
+  }
+}
+
+/// True if `x` can be made into `y` by inserting, removing or replacing one character.
+fn one_edit_apart(x: &[u8], y: &[u8]) -> bool
+{
+  let x: Vec<char> = String::from_utf8_lossy(x).chars().collect();
+  let y: Vec<char> = String::from_utf8_lossy(y).chars().collect();
+  let (short, long) = if x.len() <= y.len() {
+    (&x, &y)
+  } else {
+    (&y, &x)
+  };
+
+  match long.len() - short.len() {
+    0 => {
+      short
+        .iter()
+        .zip(long.iter())
+        .filter(|(a, b)| a != b)
+        .count()
+        == 1
+    }
+    1 => {
+      let prefix = short
+        .iter()
+        .zip(long.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+      short[prefix..] == long[prefix + 1..]
+    }
+    _ => false,
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Error
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub enum SearchError
+{
+  UnrecognisedCriterion(String),
+}
+
+impl Display for SearchError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use SearchError::*;
+
+    match self {
+      UnrecognisedCriterion(s) => write!(f, "criterion '{}' is unrecognised", s),
+    }
+  }
+}
+
+impl Error for SearchError {}
@@ -0,0 +1,102 @@
+use super::*;
+
+fn unseen() -> Vec<String>
+{
+  (0..256).map(|i| format!("w{}", i)).collect()
+}
+
+#[test]
+fn found_seeds_meet_criteria()
+{
+  let candidates = SearchBuilder::default()
+    .seeds(Seeds::Range(0..2000))
+    .criteria(vec![Criterion::NoSeenWithin(10)])
+    .trials(10)
+    .threads(4)
+    .build()
+    .unwrap()
+    .run(&unseen())
+    .unwrap();
+
+  assert!(!candidates.is_empty());
+  for candidate in candidates {
+    let mut game = Game::new(
+      candidate.seed,
+      0.4.try_into().unwrap(),
+      UnseenSetID::Unspecified,
+      unseen(),
+    );
+    for _ in 0..10 {
+      game.next().unwrap();
+      assert!(!game.is_current_seen().unwrap());
+      game.commit_unseen().unwrap();
+    }
+  }
+}
+
+#[test]
+fn seeds_running_out_of_unseen_are_scanned_until_then()
+{
+  let unseen: Vec<String> = unseen().into_iter().take(8).collect();
+  let candidates = SearchBuilder::default()
+    .seeds(Seeds::Range(0..50))
+    .trials(100)
+    .build()
+    .unwrap()
+    .run(&unseen)
+    .unwrap();
+
+  assert_eq!(candidates.len(), 10);
+}
+
+#[test]
+fn result_does_not_depend_on_threads()
+{
+  let search = |threads| {
+    SearchBuilder::default()
+      .seeds(Seeds::Random {
+        seed: 3,
+        count: 500,
+      })
+      .criteria(vec![Criterion::MinSeenDensity(0.3)])
+      .trials(30)
+      .threads(threads)
+      .limit(5)
+      .build()
+      .unwrap()
+      .run(&unseen())
+      .unwrap()
+  };
+  let candidates = search(1);
+  assert_eq!(candidates.len(), 5);
+  assert_eq!(candidates, search(3));
+  assert!(candidates
+    .windows(2)
+    .all(|w| w[0].lure_density >= w[1].lure_density));
+}
+
+#[test]
+fn parse_criterion()
+{
+  assert_eq!(
+    "no-seen-within:10".parse::<Criterion>().unwrap(),
+    Criterion::NoSeenWithin(10)
+  );
+  assert_eq!(
+    "min-lure-density:0.25".parse::<Criterion>().unwrap(),
+    Criterion::MinLureDensity(0.25)
+  );
+  assert!("no-seen-within".parse::<Criterion>().is_err());
+  assert!("shortest:3".parse::<Criterion>().is_err());
+}
+
+#[test]
+fn edits()
+{
+  assert!(one_edit_apart(b"main", b"mais"));
+  assert!(one_edit_apart(b"est", b"et"));
+  assert!(one_edit_apart("été".as_bytes(), "étés".as_bytes()));
+  assert!(!one_edit_apart(b"main", b"main"));
+  assert!(!one_edit_apart(b"main", b"mi"));
+  assert!(!one_edit_apart(b"abc", b"bca"));
+}
@@ -0,0 +1,14 @@
+mod player;
+mod simulation;
+mod summary;
+
+pub use player::{
+  Memory,
+  Player,
+  PlayerError,
+};
+pub use simulation::{
+  Simulation,
+  SimulationBuilder,
+};
+pub use summary::Summary;
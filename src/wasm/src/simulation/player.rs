@@ -0,0 +1,237 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+  collections::{
+    HashMap,
+    VecDeque,
+  },
+  error::Error,
+  fmt::Display,
+  str::FromStr,
+};
+
+use crate::rng::Konadare192PxPlusPlus;
+
+/// A uniformly distributed number in range [0.0, 1.0).
+pub(super) fn uniform(rng: &mut Konadare192PxPlusPlus) -> f64
+{
+  (rng.next() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// -------------------------------------------------------------------------------------------------
+// Memory
+// -------------------------------------------------------------------------------------------------
+
+/// How a `Player` remembers the elements it has been shown.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Memory
+{
+  /// Remembers nothing.
+  None,
+
+  /// Remembers every element.
+  Perfect,
+
+  /// Remembers the given amount of most recently shown elements.
+  Capacity(usize),
+
+  /// The chance of remembering an element halves for each given amount of commits since it was
+  /// last shown.
+  Forgetting(f64),
+}
+
+// -------------------------------------------------------------------------------------------------
+// Player
+// -------------------------------------------------------------------------------------------------
+
+/// A synthetic player. An element that is remembered is guessed as seen, otherwise the player
+/// guesses seen with the probability `bias`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Player
+{
+  memory: Memory,
+  bias: f64,
+}
+
+impl Player
+{
+  pub fn new(memory: Memory, bias: f64) -> Player
+  {
+    Player { memory, bias }
+  }
+
+  /// A player that never makes a mistake in `GameMode::Classic`.
+  pub fn perfect() -> Player
+  {
+    Player::new(Memory::Perfect, 0.0)
+  }
+
+  /// A player that only guesses, answering seen with the probability `bias`.
+  pub fn biased(bias: f64) -> Player
+  {
+    Player::new(Memory::None, bias)
+  }
+
+  pub fn memory(&self) -> &Memory
+  {
+    &self.memory
+  }
+
+  pub fn bias(&self) -> f64
+  {
+    self.bias
+  }
+
+  /// The state of the player during a game.
+  pub(super) fn recollection(&self) -> Recollection
+  {
+    Recollection {
+      player: self.clone(),
+      shown_at: HashMap::new(),
+      recent: VecDeque::new(),
+    }
+  }
+}
+
+impl Display for Player
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    match self.memory {
+      Memory::None => write!(f, "biased:{}", self.bias),
+      Memory::Perfect => write!(f, "perfect"),
+      Memory::Capacity(k) => write!(f, "capacity:{}", k),
+      Memory::Forgetting(h) => write!(f, "forgetting:{}", h),
+    }?;
+    match (&self.memory, self.bias) {
+      (Memory::None, _) | (_, 0.0) => Ok(()),
+      _ => write!(f, "@{}", self.bias),
+    }
+  }
+}
+
+impl FromStr for Player
+{
+  type Err = PlayerError;
+
+  /// Parses `perfect`, `capacity:<k>`, `forgetting:<half-life>` or `biased:<bias>`. Players with
+  /// memory can be given a bias by appending `@<bias>`, as in `capacity:40@0.2`.
+  fn from_str(s: &str) -> Result<Self, Self::Err>
+  {
+    let invalid = || PlayerError::Unrecognised(s.into());
+
+    let (memory, bias) = match s.split_once('@') {
+      Some((memory, bias)) => (memory, Some(bias.parse::<f64>().map_err(|_| invalid())?)),
+      None => (s, None),
+    };
+    let (name, value) = memory.split_once(':').unwrap_or((memory, ""));
+
+    let player = match (name, bias) {
+      ("perfect", _) => Player::new(Memory::Perfect, 0.0),
+      ("capacity", _) => Player::new(Memory::Capacity(value.parse().map_err(|_| invalid())?), 0.0),
+      ("forgetting", _) => Player::new(
+        Memory::Forgetting(value.parse().map_err(|_| invalid())?),
+        0.0,
+      ),
+      ("biased", None) => Player::biased(value.parse().map_err(|_| invalid())?),
+      _ => return Err(invalid()),
+    };
+    let player = Player {
+      bias: bias.unwrap_or(player.bias),
+      ..player
+    };
+
+    if (0.0..=1.0).contains(&player.bias) {
+      Ok(player)
+    } else {
+      Err(invalid())
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Recollection
+// -------------------------------------------------------------------------------------------------
+
+/// What a `Player` has been shown during a game.
+pub(super) struct Recollection
+{
+  player: Player,
+  // The index of the commit each element was last shown for.
+  shown_at: HashMap<Vec<u8>, usize>,
+  // The most recently shown elements when the memory is `Memory::Capacity`.
+  recent: VecDeque<Vec<u8>>,
+}
+
+impl Recollection
+{
+  /// Marks `x` as shown for commit `index`.
+  pub fn show(&mut self, x: &[u8], index: usize)
+  {
+    self.shown_at.insert(x.to_vec(), index);
+    if let Memory::Capacity(k) = self.player.memory {
+      self.recent.retain(|y| y != x);
+      self.recent.push_back(x.to_vec());
+      while self.recent.len() > k {
+        self.recent.pop_front();
+      }
+    }
+  }
+
+  /// True if `x` is remembered at commit `index`.
+  pub fn remembers(&self, x: &[u8], index: usize, rng: &mut Konadare192PxPlusPlus) -> bool
+  {
+    let shown_at = match self.shown_at.get(x) {
+      Some(&i) => i,
+      None => return false,
+    };
+    match self.player.memory {
+      Memory::None => false,
+      Memory::Perfect => true,
+      Memory::Capacity(_) => self.recent.iter().any(|y| y == x),
+      Memory::Forgetting(h) => uniform(rng) < 0.5f64.powf((index - shown_at) as f64 / h),
+    }
+  }
+
+  /// Guesses if `x` is seen at commit `index`.
+  pub fn guess(&self, x: &[u8], index: usize, rng: &mut Konadare192PxPlusPlus) -> bool
+  {
+    self.remembers(x, index, rng) || uniform(rng) < self.player.bias
+  }
+
+  /// Picks the element of the pair `(x, y)` believed to be seen, returning 0 for `x` and 1 for
+  /// `y`. If both or none are remembered the choice is random.
+  pub fn choose(&self, x: &[u8], y: &[u8], index: usize, rng: &mut Konadare192PxPlusPlus) -> usize
+  {
+    match (self.remembers(x, index, rng), self.remembers(y, index, rng)) {
+      (true, false) => 0,
+      (false, true) => 1,
+      _ => (rng.next() & 1) as usize,
+    }
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Error
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub enum PlayerError
+{
+  Unrecognised(String),
+}
+
+impl Display for PlayerError
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+  {
+    use PlayerError::*;
+
+    match self {
+      Unrecognised(s) => write!(f, "player '{}' is unrecognised", s),
+    }
+  }
+}
+
+impl Error for PlayerError {}
@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn parse_and_display()
+{
+  for (s, player) in [
+    ("perfect", Player::perfect()),
+    ("capacity:40", Player::new(Memory::Capacity(40), 0.0)),
+    ("capacity:40@0.2", Player::new(Memory::Capacity(40), 0.2)),
+    (
+      "forgetting:12.5",
+      Player::new(Memory::Forgetting(12.5), 0.0),
+    ),
+    ("biased:0.3", Player::biased(0.3)),
+  ] {
+    assert_eq!(s.parse::<Player>().unwrap(), player);
+    assert_eq!(player.to_string(), s);
+  }
+  for s in ["", "perfectly", "capacity", "biased:0.3@0.1", "biased:2"] {
+    assert!(s.parse::<Player>().is_err());
+  }
+}
+
+#[test]
+fn capacity_forgets_oldest()
+{
+  let mut rng = Konadare192PxPlusPlus::from_seed(0);
+  let mut recollection = Player::new(Memory::Capacity(2), 0.0).recollection();
+  recollection.show(b"a", 0);
+  recollection.show(b"b", 1);
+  recollection.show(b"a", 2);
+  recollection.show(b"c", 3);
+  assert!(recollection.remembers(b"a", 4, &mut rng));
+  assert!(!recollection.remembers(b"b", 4, &mut rng));
+  assert!(recollection.remembers(b"c", 4, &mut rng));
+}
+
+#[test]
+fn forgetting_halves_with_half_life()
+{
+  let mut rng = Konadare192PxPlusPlus::from_seed(0);
+  let mut recollection = Player::new(Memory::Forgetting(10.0), 0.0).recollection();
+  recollection.show(b"a", 0);
+  let remembered = (0..10000)
+    .filter(|_| recollection.remembers(b"a", 10, &mut rng))
+    .count();
+  assert!((4800..5200).contains(&remembered));
+}
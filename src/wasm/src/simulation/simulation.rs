@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+  coder::UnseenSetID,
+  game::{
+    Game,
+    GameError,
+    GameMode,
+    SeenThreshold,
+  },
+  game_over::{
+    SeenUnseen,
+    Stats,
+  },
+  rng::Konadare192PxPlusPlus,
+  simulation::{
+    Player,
+    Summary,
+  },
+};
+
+/// Mixed into the seed of a game to seed the random choices of the player.
+const PLAYER_SEED: u64 = 9404431528513146941;
+
+/// Plays many games with synthetic players. Every combination of seen threshold and player plays
+/// the same sequence of seeds, which makes the summaries comparable.
+#[derive(Builder, Clone, Debug)]
+pub struct Simulation
+{
+  #[builder(default = "UnseenSetID::Unspecified")]
+  unseen_set_id: UnseenSetID,
+
+  #[builder(default = "vec![0.4.try_into().unwrap()]")]
+  seen_thresholds: Vec<SeenThreshold>,
+
+  #[builder(default)]
+  mode: GameMode,
+
+  #[builder(default = "vec![Player::perfect()]")]
+  players: Vec<Player>,
+
+  /// Amount of games played by each combination of seen threshold and player.
+  #[builder(default = "100")]
+  games: usize,
+
+  /// Seeds the seeds of the games.
+  #[builder(default = "0")]
+  seed: u64,
+
+  /// Games are stopped after this many commits.
+  #[builder(default = "10000")]
+  max_commits: usize,
+}
+
+impl Simulation
+{
+  /// Plays the games with elements from `unseen` and returns a summary for each combination of
+  /// seen threshold and player.
+  pub fn run<T>(&self, unseen: &[T]) -> Result<Vec<Summary>, GameError>
+  where
+    T: Clone + PartialEq + AsRef<[u8]>,
+  {
+    let mut rng = Konadare192PxPlusPlus::from_seed(self.seed);
+    let seeds: Vec<u64> = (0..self.games).map(|_| rng.next()).collect();
+
+    let mut summaries = Vec::new();
+    for seen_threshold in &self.seen_thresholds {
+      for player in &self.players {
+        let mut summary = Summary::new(self.unseen_set_id.clone(), *seen_threshold, player.clone());
+        for &seed in &seeds {
+          self.play(seed, *seen_threshold, player, unseen, &mut summary)?;
+        }
+        summaries.push(summary);
+      }
+    }
+    Ok(summaries)
+  }
+
+  fn play<T>(
+    &self,
+    seed: u64,
+    seen_threshold: SeenThreshold,
+    player: &Player,
+    unseen: &[T],
+    summary: &mut Summary,
+  ) -> Result<(), GameError>
+  where
+    T: Clone + PartialEq + AsRef<[u8]>,
+  {
+    use SeenUnseen::*;
+
+    let mut game = Game::with_mode(
+      seed,
+      seen_threshold,
+      self.unseen_set_id.clone(),
+      unseen.to_vec(),
+      self.mode.clone(),
+    );
+    let mut rng = Konadare192PxPlusPlus::from_seed(seed ^ PLAYER_SEED);
+    let mut recollection = player.recollection();
+    let mut stats = Stats::default();
+
+    while !game.finished() && game.commit_count() < self.max_commits {
+      let index = game.commit_count();
+
+      if game.expects_pair() {
+        let (x, y) = match game.next_pair() {
+          Ok((x, y)) => (x.clone(), y.clone()),
+          Err(GameError::UnseenEmpty) => break,
+          Err(e) => return Err(e),
+        };
+        game.commit_choice(recollection.choose(x.as_ref(), y.as_ref(), index, &mut rng))?;
+        recollection.show(x.as_ref(), index);
+        recollection.show(y.as_ref(), index);
+      } else {
+        let x = match game.next() {
+          Ok(x) => x.clone(),
+          Err(GameError::UnseenEmpty) => break,
+          Err(e) => return Err(e),
+        };
+        let actual = game.is_current_seen().unwrap();
+        let guess = recollection.guess(x.as_ref(), index, &mut rng);
+        if guess {
+          game.commit_seen()?;
+        } else {
+          game.commit_unseen()?;
+        }
+        stats.record(
+          if actual { &Seen } else { &Unseen },
+          if guess { &Seen } else { &Unseen },
+          None,
+        );
+        recollection.show(x.as_ref(), index);
+      }
+    }
+
+    summary.add(&game, &stats);
+    Ok(())
+  }
+}
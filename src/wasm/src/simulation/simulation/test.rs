@@ -0,0 +1,92 @@
+use std::num::NonZeroUsize;
+
+use super::*;
+use crate::simulation::Memory;
+
+fn unseen() -> Vec<String>
+{
+  (0..256).map(|i| format!("w{}", i)).collect()
+}
+
+#[test]
+fn perfect_player_plays_until_unseen_is_empty()
+{
+  let summaries = SimulationBuilder::default()
+    .games(8)
+    .build()
+    .unwrap()
+    .run(&unseen())
+    .unwrap();
+
+  assert_eq!(summaries.len(), 1);
+  let summary = &summaries[0];
+  assert_eq!(summary.games(), 8);
+  assert_eq!(summary.unfinished(), 8);
+  assert_eq!(summary.stats().misses(), 0);
+  assert_eq!(summary.stats().false_alarms(), 0);
+  assert!(summary.scores().iter().all(|&x| x >= 256));
+}
+
+#[test]
+fn score_histogram_counts_every_game()
+{
+  let summaries = SimulationBuilder::default()
+    .games(32)
+    .players(vec![Player::biased(0.5)])
+    .build()
+    .unwrap()
+    .run(&unseen())
+    .unwrap();
+
+  let summary = &summaries[0];
+  let width = NonZeroUsize::new(4).unwrap();
+  let histogram = summary.score_histogram(width);
+  assert_eq!(histogram.iter().sum::<usize>(), summary.games());
+  assert_eq!(
+    histogram.len(),
+    summary.scores().iter().max().unwrap() / 4 + 1
+  );
+  assert_ne!(histogram.last(), Some(&0));
+}
+
+#[test]
+fn better_memory_scores_higher()
+{
+  let summaries = SimulationBuilder::default()
+    .seen_thresholds(vec![0.3.try_into().unwrap(), 0.5.try_into().unwrap()])
+    .players(vec![
+      Player::biased(0.5),
+      Player::new(Memory::Capacity(8), 0.0),
+      Player::new(Memory::Capacity(64), 0.0),
+    ])
+    .games(32)
+    .seed(1)
+    .build()
+    .unwrap()
+    .run(&unseen())
+    .unwrap();
+
+  assert_eq!(summaries.len(), 6);
+  for x in summaries.chunks(3) {
+    assert!(x[0].mean_score() < x[1].mean_score());
+    assert!(x[1].mean_score() < x[2].mean_score());
+    assert_eq!(x[0].unfinished(), 0);
+  }
+}
+
+#[test]
+fn same_seed_gives_same_summaries()
+{
+  let simulation = SimulationBuilder::default()
+    .mode(GameMode::ForcedChoice)
+    .players(vec![Player::new(Memory::Forgetting(16.0), 0.2)])
+    .games(16)
+    .seed(2)
+    .build()
+    .unwrap();
+
+  let a = simulation.run(&unseen()).unwrap();
+  let b = simulation.run(&unseen()).unwrap();
+  assert_eq!(a[0].scores(), b[0].scores());
+  assert_eq!(a[0].lengths(), b[0].lengths());
+}
@@ -0,0 +1,140 @@
+use std::num::NonZeroUsize;
+
+use crate::{
+  coder::UnseenSetID,
+  game::{
+    Game,
+    SeenThreshold,
+  },
+  game_over::Stats,
+  simulation::Player,
+};
+
+/// The outcome of the games played by a `Player` with the same `UnseenSetID` and
+/// `SeenThreshold`.
+#[derive(Clone, Debug)]
+pub struct Summary
+{
+  unseen_set_id: UnseenSetID,
+  seen_threshold: SeenThreshold,
+  player: Player,
+  scores: Vec<usize>,
+  lengths: Vec<usize>,
+  unfinished: usize,
+  stats: Stats,
+}
+
+impl Summary
+{
+  pub(super) fn new(
+    unseen_set_id: UnseenSetID,
+    seen_threshold: SeenThreshold,
+    player: Player,
+  ) -> Summary
+  {
+    Summary {
+      unseen_set_id,
+      seen_threshold,
+      player,
+      scores: Vec::new(),
+      lengths: Vec::new(),
+      unfinished: 0,
+      stats: Stats::default(),
+    }
+  }
+
+  /// Adds a played game and the stats of its commits.
+  pub(super) fn add<T>(&mut self, game: &Game<T>, stats: &Stats)
+  {
+    self.scores.push(game.score());
+    self.lengths.push(game.commit_count());
+    if !game.finished() {
+      self.unfinished += 1;
+    }
+    self.stats.merge(stats);
+  }
+
+  pub fn unseen_set_id(&self) -> &UnseenSetID
+  {
+    &self.unseen_set_id
+  }
+
+  pub fn seen_threshold(&self) -> SeenThreshold
+  {
+    self.seen_threshold
+  }
+
+  pub fn player(&self) -> &Player
+  {
+    &self.player
+  }
+
+  /// Amount of games played.
+  pub fn games(&self) -> usize
+  {
+    self.scores.len()
+  }
+
+  /// The score of each game.
+  pub fn scores(&self) -> &[usize]
+  {
+    &self.scores
+  }
+
+  /// The amount of commits of each game.
+  pub fn lengths(&self) -> &[usize]
+  {
+    &self.lengths
+  }
+
+  /// Amount of games that ended before the player ran out of lives, because the unseen elements
+  /// ran out or the commit limit was reached.
+  pub fn unfinished(&self) -> usize
+  {
+    self.unfinished
+  }
+
+  /// Signal detection statistics of all commits. Commits that are a choice between two elements
+  /// are not included.
+  pub fn stats(&self) -> &Stats
+  {
+    &self.stats
+  }
+
+  pub fn mean_score(&self) -> f64
+  {
+    mean(&self.scores)
+  }
+
+  pub fn mean_length(&self) -> f64
+  {
+    mean(&self.lengths)
+  }
+
+  /// The score at quantile `q` in range [0.0, 1.0], where 0.5 is the median.
+  pub fn score_quantile(&self, q: f64) -> usize
+  {
+    let mut scores = self.scores.clone();
+    scores.sort_unstable();
+    scores
+      .get(((scores.len().saturating_sub(1)) as f64 * q).round() as usize)
+      .cloned()
+      .unwrap_or(0)
+  }
+
+  /// The amount of games with a score in each range of `width` scores, starting at 0.
+  pub fn score_histogram(&self, width: NonZeroUsize) -> Vec<usize>
+  {
+    let width = width.get();
+    let mut histogram = vec![0; self.scores.iter().max().map_or(0, |x| x / width + 1)];
+    for x in &self.scores {
+      histogram[x / width] += 1;
+    }
+    histogram
+  }
+}
+
+fn mean(xs: &[usize]) -> f64
+{
+  xs.iter().sum::<usize>() as f64 / xs.len().max(1) as f64
+}
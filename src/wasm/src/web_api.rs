@@ -1,15 +1,28 @@
+mod analytics;
+mod challenge;
 mod encoded_game_over;
 mod game;
+mod game_mode;
 mod game_over;
+mod ghost_race;
+mod history;
 mod rng;
 mod unseen_set_id;
 
+pub use analytics::Analytics;
+pub use challenge::Challenge;
 pub use encoded_game_over::EncodedGameOver;
 pub use game::Game;
+pub use game_mode::GameMode;
 pub use game_over::{
   Commit,
   GameOver,
 };
+pub use ghost_race::GhostRace;
+pub use history::{
+  History,
+  HistoryRecord,
+};
 pub use rng::{
   Konadare192PxPlusPlus,
   KSINK,
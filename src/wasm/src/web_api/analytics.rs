@@ -0,0 +1,194 @@
+use wasm_bindgen::prelude::{
+  wasm_bindgen,
+  JsValue,
+};
+
+use crate::{
+  analytics,
+  game_over,
+  web_api::{
+    EncodedGameOver,
+    GameOver,
+    UnseenSetIDPrimitive,
+  },
+};
+
+type Inner = String;
+
+/// Aggregated statistics over the games of a player, for charting.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct Analytics(analytics::Analytics<Inner>);
+
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+impl Analytics
+{
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> Analytics
+  {
+    Analytics::default()
+  }
+
+  /// Adds a game played at `timestamp`, in milliseconds since the unix epoch.
+  #[wasm_bindgen]
+  pub fn add(&mut self, game_over: &GameOver, timestamp: f64)
+  {
+    self.0.add(timestamp as u64, game_over.inner());
+  }
+
+  /// Decodes and adds a game played at `timestamp`, such as a record of a `History`.
+  #[wasm_bindgen]
+  pub fn addEncoded(
+    &mut self,
+    encoded: &EncodedGameOver,
+    unseen: Vec<JsValue>,
+    timestamp: f64,
+  ) -> Result<(), String>
+  {
+    let unseen = unseen.into_iter().map(|x| x.as_string().unwrap()).collect();
+    let game_over: game_over::GameOver<Inner> = (encoded.inner().clone(), unseen)
+      .try_into()
+      .map_err(|e| format!("{}", e))?;
+    self.0.add(timestamp as u64, &game_over);
+    Ok(())
+  }
+
+  /// The timestamps of the games in chronological order.
+  #[wasm_bindgen]
+  pub fn timestamps(&self) -> Vec<f64>
+  {
+    self
+      .0
+      .games()
+      .iter()
+      .map(|x| x.timestamp() as f64)
+      .collect()
+  }
+
+  /// The scores of the games in chronological order.
+  #[wasm_bindgen]
+  pub fn scores(&self) -> Vec<usize>
+  {
+    self.0.games().iter().map(|x| x.score()).collect()
+  }
+
+  /// The mean score of each game and the `window - 1` games before it.
+  #[wasm_bindgen]
+  pub fn rollingAverage(&self, window: usize) -> Vec<f64>
+  {
+    self.0.rolling_average(window)
+  }
+
+  /// The d′ of each game, `NaN` for games without both seen and unseen commits.
+  #[wasm_bindgen]
+  pub fn dPrimeTrend(&self) -> Vec<f64>
+  {
+    self
+      .0
+      .d_prime_trend()
+      .into_iter()
+      .map(|x| x.unwrap_or(f64::NAN))
+      .collect()
+  }
+
+  /// The amount of incorrect commits at each commit index.
+  #[wasm_bindgen]
+  pub fn errorPositions(&self) -> Vec<usize>
+  {
+    self.0.error_positions().to_vec()
+  }
+
+  /// The best and mean score of each unseen set.
+  #[wasm_bindgen]
+  pub fn sets(&self) -> Vec<SetSummary>
+  {
+    self.0.sets().into_iter().map(SetSummary).collect()
+  }
+
+  /// The `limit` words with at least `min_commits` commits that the player misjudges the most.
+  #[wasm_bindgen]
+  pub fn hardestWords(&self, limit: usize, min_commits: usize) -> Vec<WordDifficulty>
+  {
+    self
+      .0
+      .words()
+      .ranked(min_commits)
+      .into_iter()
+      .take(limit)
+      .map(|(word, stats)| WordDifficulty(word.clone(), stats.clone()))
+      .collect()
+  }
+}
+
+/// The scores of the games played with an unseen set.
+#[wasm_bindgen]
+pub struct SetSummary(analytics::SetSummary);
+
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+impl SetSummary
+{
+  #[wasm_bindgen]
+  pub fn unseenSetID(&self) -> UnseenSetIDPrimitive
+  {
+    (&self.0.unseen_set_id).into()
+  }
+
+  #[wasm_bindgen]
+  pub fn games(&self) -> usize
+  {
+    self.0.games
+  }
+
+  #[wasm_bindgen]
+  pub fn best(&self) -> usize
+  {
+    self.0.best
+  }
+
+  #[wasm_bindgen]
+  pub fn mean(&self) -> f64
+  {
+    self.0.mean
+  }
+}
+
+/// How a word was judged.
+#[wasm_bindgen]
+pub struct WordDifficulty(Inner, analytics::WordStats);
+
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+impl WordDifficulty
+{
+  #[wasm_bindgen]
+  pub fn word(&self) -> Inner
+  {
+    self.0.clone()
+  }
+
+  #[wasm_bindgen]
+  pub fn commits(&self) -> usize
+  {
+    self.1.commits()
+  }
+
+  #[wasm_bindgen]
+  pub fn misses(&self) -> usize
+  {
+    self.1.misses()
+  }
+
+  #[wasm_bindgen]
+  pub fn falseAlarms(&self) -> usize
+  {
+    self.1.false_alarms()
+  }
+
+  #[wasm_bindgen]
+  pub fn errorRate(&self) -> f64
+  {
+    self.1.error_rate()
+  }
+}
@@ -0,0 +1,169 @@
+use wasm_bindgen::prelude::{
+  wasm_bindgen,
+  JsValue,
+};
+
+use crate::{
+  coder::{
+    self,
+    ChallengeBuilder,
+    SealedChallenge,
+  },
+  web_api,
+};
+
+/// The settings of a game without any results. Results of the challenge reference it so that they
+/// can be compared.
+#[wasm_bindgen]
+pub struct Challenge(SealedChallenge, coder::Challenge);
+
+impl TryFrom<SealedChallenge> for Challenge
+{
+  type Error = String;
+
+  fn try_from(sealed: SealedChallenge) -> Result<Self, Self::Error>
+  {
+    let challenge = sealed.open().map_err(|e| format!("{}", e))?;
+    Ok(Challenge(sealed, challenge))
+  }
+}
+
+#[wasm_bindgen]
+#[allow(non_snake_case)]
+impl Challenge
+{
+  /// Initialize a new `Challenge`.
+  ///
+  /// @param seed Seed used for rng.
+  /// @param seenRatio The ratio of seen elements that will be generated.
+  /// @param lives The amount of lives the game starts with.
+  /// @param mode The mode of the game.
+  #[wasm_bindgen(constructor)]
+  pub fn new(
+    seed: u64,
+    seenRatio: f64,
+    unseen_set_id: web_api::UnseenSetIDPrimitive,
+    lives: usize,
+    mode: &web_api::GameMode,
+  ) -> Result<Challenge, String>
+  {
+    let challenge = ChallengeBuilder::default()
+      .seed(seed)
+      .seen_threshold(seenRatio.try_into().map_err(|e| format!("{}", e))?)
+      .unseen_set_id(unseen_set_id.into())
+      .lives(lives)
+      .mode(mode.inner().clone())
+      .build()
+      .map_err(|e| format!("{}", e))?;
+    SealedChallenge::new(&challenge)
+      .map(|sealed| Challenge(sealed, challenge))
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// The challenge of playing the same game as `gameOver`.
+  #[wasm_bindgen]
+  pub fn fromGameOver(gameOver: &web_api::GameOver) -> Result<Challenge, String>
+  {
+    let challenge = coder::Challenge::from(gameOver.inner());
+    SealedChallenge::new(&challenge)
+      .map(|sealed| Challenge(sealed, challenge))
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// Parses and verifies a challenge URL or its search query.
+  #[wasm_bindgen]
+  pub fn fromURL(url: String) -> Result<Challenge, String>
+  {
+    url
+      .parse::<SealedChallenge>()
+      .map_err(|e| format!("{}", e))?
+      .try_into()
+  }
+
+  #[wasm_bindgen]
+  pub fn asURLSearchParams(&self) -> String
+  {
+    serde_urlencoded::to_string(&self.0).unwrap()
+  }
+
+  /// The ID that results of the challenge reference.
+  #[wasm_bindgen]
+  pub fn id(&self) -> u64
+  {
+    self.0.id()
+  }
+
+  #[wasm_bindgen]
+  pub fn seed(&self) -> u64
+  {
+    self.1.seed()
+  }
+
+  #[wasm_bindgen]
+  pub fn seenRatio(&self) -> f64
+  {
+    self.1.seen_threshold().ratio()
+  }
+
+  #[wasm_bindgen]
+  pub fn unseenSetID(&self) -> web_api::UnseenSetIDPrimitive
+  {
+    self.1.unseen_set_id().into()
+  }
+
+  #[wasm_bindgen]
+  pub fn lives(&self) -> usize
+  {
+    self.1.lives()
+  }
+
+  #[wasm_bindgen]
+  pub fn mode(&self) -> web_api::GameMode
+  {
+    self.1.mode().clone().into()
+  }
+
+  /// Starts the game of the challenge.
+  ///
+  /// @param unseen List of unseen values.
+  #[wasm_bindgen]
+  pub fn game(&self, unseen: Vec<JsValue>) -> web_api::Game
+  {
+    self
+      .1
+      .game(unseen.into_iter().map(|x| x.as_string().unwrap()).collect())
+      .into()
+  }
+
+  /// Encodes a finished game of the challenge with a reference to it.
+  #[wasm_bindgen]
+  pub fn encodeResult(
+    &self,
+    gameOver: &web_api::GameOver,
+  ) -> Result<web_api::EncodedGameOver, String>
+  {
+    if !self.1.matches(gameOver.inner()) {
+      return Err(format!("{}", coder::ChallengeError::SettingsMismatch));
+    }
+    let encoded = web_api::EncodedGameOver::new(gameOver)?;
+    Ok(self.0.sign(encoded.inner().clone()).into())
+  }
+
+  /// Decodes a result of the challenge. Throws if it does not reference the challenge or was not
+  /// played with its settings.
+  ///
+  /// @param url The share URL of the result.
+  /// @param unseen List of unseen values.
+  #[wasm_bindgen]
+  pub fn decodeResult(&self, url: String, unseen: Vec<JsValue>)
+    -> Result<web_api::GameOver, String>
+  {
+    let sealed = web_api::EncodedGameOver::fromURL(url)?.inner().clone();
+    let unseen = unseen.into_iter().map(|x| x.as_string().unwrap()).collect();
+    self
+      .0
+      .result(sealed, unseen)
+      .map(web_api::GameOver::from)
+      .map_err(|e| format!("{}", e))
+  }
+}
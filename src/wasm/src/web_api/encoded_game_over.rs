@@ -1,4 +1,3 @@
-use url::Url;
 use wasm_bindgen::prelude::{
   wasm_bindgen,
   JsValue,
@@ -7,7 +6,7 @@ use wasm_bindgen::prelude::{
 use crate::{
   coder::{
     self,
-    GameOverCoderV01,
+    LatestCoder,
     SealedEncodedGameOver,
   },
   game_over,
@@ -17,6 +16,22 @@ use crate::{
 #[wasm_bindgen]
 pub struct EncodedGameOver(coder::SealedEncodedGameOver);
 
+impl EncodedGameOver
+{
+  pub(crate) fn inner(&self) -> &coder::SealedEncodedGameOver
+  {
+    &self.0
+  }
+}
+
+impl From<coder::SealedEncodedGameOver> for EncodedGameOver
+{
+  fn from(sealed: coder::SealedEncodedGameOver) -> Self
+  {
+    EncodedGameOver(sealed)
+  }
+}
+
 #[allow(non_snake_case)]
 #[wasm_bindgen]
 impl EncodedGameOver
@@ -24,7 +39,7 @@ impl EncodedGameOver
   #[wasm_bindgen(constructor)]
   pub fn new(game_over: &web_api::GameOver) -> Result<EncodedGameOver, String>
   {
-    coder::SealedEncodedGameOver::new::<GameOverCoderV01, _>(game_over.inner())
+    coder::SealedEncodedGameOver::new::<LatestCoder, _>(game_over.inner())
       .map(|x| EncodedGameOver(x))
       .map_err(|e| format!("{}", e))
   }
@@ -35,18 +50,53 @@ impl EncodedGameOver
     serde_urlencoded::to_string(&self.0).unwrap()
   }
 
+  /// The code as the fragment of a share URL, `g=...`, without the leading '#'.
+  #[wasm_bindgen]
+  pub fn asURLFragment(&self) -> Result<String, String>
+  {
+    self
+      .0
+      .to_token()
+      .map(|token| format!("g={}", token))
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// The code as a single token for the last path segment of a share URL.
+  #[wasm_bindgen]
+  pub fn asURLPathSegment(&self) -> Result<String, String>
+  {
+    self.0.to_token().map_err(|e| format!("{}", e))
+  }
+
+  /// Parses a share URL with the code in its search query, its fragment or its last path segment.
   #[wasm_bindgen]
   pub fn fromURL(url: String) -> Result<EncodedGameOver, String>
   {
-    Ok(EncodedGameOver(
-      serde_urlencoded::from_str::<SealedEncodedGameOver>(
-        Url::parse(&url)
-          .map_err(|e| format!("{}", e))?
-          .query()
-          .ok_or("url has no search query")?,
-      )
-      .map_err(|e| format!("{}", e))?,
-    ))
+    url
+      .parse::<SealedEncodedGameOver>()
+      .map(EncodedGameOver)
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// Signs the code with a key supplied by the host.
+  ///
+  /// @param key The bytes of the key.
+  #[wasm_bindgen]
+  pub fn withMac(&self, key: Vec<u8>) -> EncodedGameOver
+  {
+    EncodedGameOver(self.0.clone().with_mac(&coder::MacKey::new(key)))
+  }
+
+  /// Verifies the code and its MAC.
+  ///
+  /// @param key The bytes of the key.
+  /// @returns True if the code was signed with the key and false if it is not signed.
+  #[wasm_bindgen]
+  pub fn verify(&self, key: Vec<u8>) -> Result<bool, String>
+  {
+    let encoded = coder::EncodedGameOver::try_from((self.0.clone(), &coder::MacKey::new(key)))
+      .map_err(|e| format!("{}", e))?;
+    Ok(encoded.authenticity() == coder::Authenticity::Verified)
   }
 
   #[wasm_bindgen]
@@ -66,4 +116,21 @@ impl EncodedGameOver
       (seal, unseen).try_into().map_err(|e| format!("{}", e))?;
     Ok(web_api::GameOver::from(game_over))
   }
+
+  /// Re-encodes a share code of any supported version with the latest coder. Throws unless the new
+  /// code replays exactly like the old one. A MAC of the old code is not kept.
+  ///
+  /// @param url Share URL of the code.
+  /// @param unseen The elements of the unseen set of the game.
+  #[wasm_bindgen]
+  pub fn migrate(url: String, unseen: Vec<JsValue>) -> Result<EncodedGameOver, String>
+  {
+    let seal = EncodedGameOver::fromURL(url)?.0;
+    let unseen: Vec<String> = unseen.into_iter().map(|x| x.as_string().unwrap()).collect();
+
+    seal
+      .migrate(unseen)
+      .map(EncodedGameOver)
+      .map_err(|e| format!("{}", e))
+  }
 }
@@ -11,6 +11,14 @@ use crate::{
 #[wasm_bindgen]
 pub struct Game(game::Game<String>);
 
+impl From<game::Game<String>> for Game
+{
+  fn from(game: game::Game<String>) -> Self
+  {
+    Game(game)
+  }
+}
+
 #[wasm_bindgen]
 #[allow(non_snake_case)]
 impl Game
@@ -36,6 +44,30 @@ impl Game
     )))
   }
 
+  /// Initialize a new `Game` played with the rules of `mode`.
+  ///
+  /// @param seed Seed used for rng.
+  /// @param seenRatio The ratio of seen elements that will be generated.
+  /// @param unseen List of unseen values.
+  /// @param mode The mode of the game.
+  #[wasm_bindgen]
+  pub fn withMode(
+    seed: u64,
+    seenRatio: f64,
+    unseen_set_id: web_api::UnseenSetIDPrimitive,
+    unseen: Vec<JsValue>,
+    mode: &web_api::GameMode,
+  ) -> Result<Game, String>
+  {
+    Ok(Game(game::Game::with_mode(
+      seed,
+      seenRatio.try_into().map_err(|e| format!("{}", e))?,
+      unseen_set_id.into(),
+      unseen.into_iter().map(|x| x.as_string().unwrap()).collect(),
+      mode.inner().clone(),
+    )))
+  }
+
   /// Generates the next element.
   #[wasm_bindgen]
   pub fn next(&mut self) -> Result<String, String>
@@ -47,6 +79,36 @@ impl Game
       .map_err(|e| format!("{}", e))
   }
 
+  /// Generates the next pair of elements in a forced choice game. Exactly one of them is seen.
+  ///
+  /// @returns The two elements.
+  #[wasm_bindgen]
+  pub fn nextPair(&mut self) -> Result<Vec<JsValue>, String>
+  {
+    self
+      .0
+      .next_pair()
+      .map(|(x, y)| vec![JsValue::from_str(x), JsValue::from_str(y)])
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// True if the next element must be generated with `nextPair`.
+  #[wasm_bindgen]
+  pub fn expectsPair(&self) -> bool
+  {
+    self.0.expects_pair()
+  }
+
+  /// Commit the element in the pair that is believed to be seen.
+  ///
+  /// @param choice 0 for the first element and 1 for the second.
+  /// @returns Boolean indicating if the commit was correct.
+  #[wasm_bindgen]
+  pub fn commitChoice(&mut self, choice: usize) -> Result<bool, String>
+  {
+    self.0.commit_choice(choice).map_err(|e| format!("{}", e))
+  }
+
   /// Commit the generated element as seen.
   ///
   /// @returns Boolean indicating if the commit was correct.
@@ -65,6 +127,41 @@ impl Game
     self.0.commit_unseen().map_err(|e| format!("{}", e))
   }
 
+  /// Commit the generated element as seen with a confidence.
+  ///
+  /// @param confidence 1 for sure, 2 for think and 3 for guess.
+  /// @returns Boolean indicating if the commit was correct.
+  #[wasm_bindgen]
+  pub fn commitSeenWithConfidence(&mut self, confidence: u8) -> Result<bool, String>
+  {
+    self
+      .0
+      .commit_seen_with_confidence(confidence.try_into().map_err(|e| format!("{}", e))?)
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// Commit the generated element as unseen with a confidence.
+  ///
+  /// @param confidence 1 for sure, 2 for think and 3 for guess.
+  /// @returns Boolean indicating if the commit was correct.
+  #[wasm_bindgen]
+  pub fn commitUnseenWithConfidence(&mut self, confidence: u8) -> Result<bool, String>
+  {
+    self
+      .0
+      .commit_unseen_with_confidence(confidence.try_into().map_err(|e| format!("{}", e))?)
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// Sets the time it took to answer the current element. It is stored with the next commit.
+  ///
+  /// @param milliseconds The reaction time.
+  #[wasm_bindgen]
+  pub fn setReactionTime(&mut self, milliseconds: u32)
+  {
+    self.0.set_reaction_time(milliseconds)
+  }
+
   /// The current score.
   ///
   /// @returns The score.
@@ -83,6 +180,24 @@ impl Game
     self.0.lives()
   }
 
+  /// The current difficulty level. Is always 0 unless the game is played in adaptive mode.
+  ///
+  /// @returns The level.
+  #[wasm_bindgen]
+  pub fn level(&self) -> u32
+  {
+    self.0.staircase().level()
+  }
+
+  /// The amount of lives the game started with.
+  ///
+  /// @returns The amount.
+  #[wasm_bindgen]
+  pub fn initialLives(&self) -> usize
+  {
+    self.0.initial_lives()
+  }
+
   /// The initial amount of lives a `Game` starts with.
   ///
   /// @returns The amount.
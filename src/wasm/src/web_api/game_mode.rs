@@ -0,0 +1,73 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::game;
+
+/// The rules deciding how the elements of a `Game` are generated.
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct GameMode(game::GameMode);
+
+impl GameMode
+{
+  pub fn inner(&self) -> &game::GameMode
+  {
+    &self.0
+  }
+}
+
+impl From<game::GameMode> for GameMode
+{
+  fn from(mode: game::GameMode) -> Self
+  {
+    GameMode(mode)
+  }
+}
+
+#[wasm_bindgen]
+#[allow(non_snake_case)]
+impl GameMode
+{
+  /// The seen ratio is fixed for the whole game.
+  #[wasm_bindgen]
+  pub fn classic() -> GameMode
+  {
+    GameMode(game::GameMode::Classic)
+  }
+
+  /// The seen ratio and the repetition lag adapts to the accuracy of the player.
+  #[wasm_bindgen]
+  pub fn adaptive() -> GameMode
+  {
+    GameMode(game::GameMode::Adaptive)
+  }
+
+  /// An element only counts as seen if it was generated within the last `k` commits. Throws if `k`
+  /// is 0, since then nothing could ever be seen.
+  #[wasm_bindgen]
+  pub fn window(k: usize) -> Result<GameMode, String>
+  {
+    match k {
+      0 => Err("the window must hold at least one commit".to_string()),
+      k => Ok(GameMode(game::GameMode::Window(k))),
+    }
+  }
+
+  /// An element counts as seen if it is the same as the element generated `n` commits ago. The
+  /// seen ratio of the game is the rate of such targets. Throws if `n` is 0, since an element can
+  /// not be compared with itself.
+  #[wasm_bindgen]
+  pub fn nBack(n: usize) -> Result<GameMode, String>
+  {
+    match n {
+      0 => Err("n must be at least one commit back".to_string()),
+      n => Ok(GameMode(game::GameMode::NBack(n))),
+    }
+  }
+
+  /// Two elements are generated where exactly one is seen and the player picks the seen one.
+  #[wasm_bindgen]
+  pub fn forcedChoice() -> GameMode
+  {
+    GameMode(game::GameMode::ForcedChoice)
+  }
+}
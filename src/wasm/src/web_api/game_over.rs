@@ -1,8 +1,12 @@
-use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::prelude::{
+  wasm_bindgen,
+  JsValue,
+};
 
 use crate::{
   game,
   game_over,
+  plausibility::Plausibility,
 };
 
 type Inner = String;
@@ -36,7 +40,16 @@ impl SeenUnseen
 #[derive(Clone)]
 pub struct Commit(game_over::Commit<Inner>);
 
+impl From<game_over::Commit<Inner>> for Commit
+{
+  fn from(commit: game_over::Commit<Inner>) -> Self
+  {
+    Commit(commit)
+  }
+}
+
 #[wasm_bindgen]
+#[allow(non_snake_case)]
 impl Commit
 {
   /// The value of the element.
@@ -66,6 +79,27 @@ impl Commit
   {
     self.0.correct()
   }
+
+  /// The second element of the pair if the commit was a choice between two elements.
+  #[wasm_bindgen]
+  pub fn alternative(&self) -> Option<Inner>
+  {
+    self.0.alternative().cloned()
+  }
+
+  /// The confidence of the guess, 1 for sure, 2 for think and 3 for guess.
+  #[wasm_bindgen]
+  pub fn confidence(&self) -> Option<u8>
+  {
+    self.0.confidence().map(u8::from)
+  }
+
+  /// The time in milliseconds it took to answer, if it was measured.
+  #[wasm_bindgen]
+  pub fn reactionTime(&self) -> Option<u32>
+  {
+    self.0.reaction_time()
+  }
 }
 
 #[wasm_bindgen]
@@ -106,6 +140,20 @@ impl GameOver
     self.0.score()
   }
 
+  /// Describes the patterns in the game that are unlikely for a human player.
+  ///
+  /// @returns A list of descriptions, empty if the game is plausible.
+  #[wasm_bindgen]
+  pub fn plausibilityFlags(&self) -> Vec<JsValue>
+  {
+    Plausibility::default()
+      .check(&self.0)
+      .flags
+      .iter()
+      .map(|x| JsValue::from_str(&x.to_string()))
+      .collect()
+  }
+
   /// Final score of the game.
   #[wasm_bindgen]
   pub fn lives(&self) -> usize
@@ -118,6 +166,107 @@ impl GameOver
   {
     GameOverIterator(self.0.iter())
   }
+
+  /// Signal detection statistics of the commits.
+  #[wasm_bindgen]
+  pub fn stats(&self) -> Stats
+  {
+    Stats(self.0.stats())
+  }
+
+  /// The commits as a plain-text table with the lives left and the score after each of them.
+  #[wasm_bindgen]
+  pub fn toTextTable(&self) -> String
+  {
+    self.0.to_text_table()
+  }
+
+  /// The commits as a Markdown table, e.g. to paste into an issue.
+  #[wasm_bindgen]
+  pub fn toMarkdownTable(&self) -> String
+  {
+    self.0.to_markdown_table()
+  }
+
+  /// The commits as CSV with a header line.
+  #[wasm_bindgen]
+  pub fn toCSV(&self) -> String
+  {
+    self.0.to_csv()
+  }
+
+  /// The commits as a JSON array with one object per commit. Not named `toJSON`, which
+  /// `JSON.stringify` would call on the game over.
+  #[wasm_bindgen]
+  pub fn toJSONString(&self) -> Result<String, String>
+  {
+    self.0.to_json().map_err(|e| e.to_string())
+  }
+}
+
+/// Signal detection statistics of a game.
+#[wasm_bindgen]
+pub struct Stats(game_over::Stats);
+
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+impl Stats
+{
+  /// Seen elements guessed as seen.
+  #[wasm_bindgen]
+  pub fn hits(&self) -> usize
+  {
+    self.0.hits()
+  }
+
+  /// Seen elements guessed as unseen.
+  #[wasm_bindgen]
+  pub fn misses(&self) -> usize
+  {
+    self.0.misses()
+  }
+
+  /// Unseen elements guessed as seen.
+  #[wasm_bindgen]
+  pub fn falseAlarms(&self) -> usize
+  {
+    self.0.false_alarms()
+  }
+
+  /// Unseen elements guessed as unseen.
+  #[wasm_bindgen]
+  pub fn correctRejections(&self) -> usize
+  {
+    self.0.correct_rejections()
+  }
+
+  /// The false alarm rates of the points in the ROC curve.
+  #[wasm_bindgen]
+  pub fn rocFalseAlarmRates(&self) -> Vec<f64>
+  {
+    self.0.roc().iter().map(|x| x.0).collect()
+  }
+
+  /// The hit rates of the points in the ROC curve.
+  #[wasm_bindgen]
+  pub fn rocHitRates(&self) -> Vec<f64>
+  {
+    self.0.roc().iter().map(|x| x.1).collect()
+  }
+
+  /// The area under the ROC curve.
+  #[wasm_bindgen]
+  pub fn auc(&self) -> Option<f64>
+  {
+    self.0.auc()
+  }
+
+  /// The sensitivity index d′.
+  #[wasm_bindgen]
+  pub fn dPrime(&self) -> Option<f64>
+  {
+    self.0.d_prime()
+  }
 }
 
 #[wasm_bindgen]
@@ -0,0 +1,234 @@
+use wasm_bindgen::prelude::{
+  wasm_bindgen,
+  JsValue,
+};
+
+use crate::{
+  game_over,
+  web_api::{
+    self,
+    EncodedGameOver,
+  },
+};
+
+type Inner = String;
+
+/// A live game played against the replay of a shared game, the ghost.
+#[wasm_bindgen]
+pub struct GhostRace(game_over::GhostRace<Inner>);
+
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+impl GhostRace
+{
+  /// Decodes the ghost from a share URL and starts a game with the same seed, seen ratio, unseen
+  /// set and mode.
+  ///
+  /// @param url The share URL of the ghost.
+  /// @param unseen List of unseen values of the unseen set of the ghost.
+  #[wasm_bindgen(constructor)]
+  pub fn new(url: String, unseen: Vec<JsValue>) -> Result<GhostRace, String>
+  {
+    let words = unseen.iter().map(|x| x.as_string().unwrap()).collect();
+    let ghost = EncodedGameOver::decode(url, unseen)?;
+    Ok(GhostRace(game_over::GhostRace::new(ghost.inner(), words)))
+  }
+
+  /// Generates the next element.
+  #[wasm_bindgen(js_name = next)]
+  pub fn next_element(&mut self) -> Result<String, String>
+  {
+    self.0.next_element().cloned().map_err(|e| format!("{}", e))
+  }
+
+  /// Generates the next pair of elements in a forced choice game.
+  ///
+  /// @returns The two elements.
+  #[wasm_bindgen]
+  pub fn nextPair(&mut self) -> Result<Vec<JsValue>, String>
+  {
+    self
+      .0
+      .next_pair()
+      .map(|(x, y)| vec![JsValue::from_str(x), JsValue::from_str(y)])
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// True if the next element must be generated with `nextPair`.
+  #[wasm_bindgen]
+  pub fn expectsPair(&self) -> bool
+  {
+    self.0.game().expects_pair()
+  }
+
+  /// Commit the generated element as seen.
+  #[wasm_bindgen]
+  pub fn commitSeen(&mut self) -> Result<GhostStep, String>
+  {
+    self
+      .0
+      .commit_seen()
+      .map(GhostStep)
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// Commit the generated element as unseen.
+  #[wasm_bindgen]
+  pub fn commitUnseen(&mut self) -> Result<GhostStep, String>
+  {
+    self
+      .0
+      .commit_unseen()
+      .map(GhostStep)
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// Commit the element in the pair that is believed to be seen.
+  ///
+  /// @param choice 0 for the first element and 1 for the second.
+  #[wasm_bindgen]
+  pub fn commitChoice(&mut self, choice: usize) -> Result<GhostStep, String>
+  {
+    self
+      .0
+      .commit_choice(choice)
+      .map(GhostStep)
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// True if the live game is over.
+  #[wasm_bindgen]
+  pub fn finished(&self) -> bool
+  {
+    self.0.game().finished()
+  }
+
+  /// The score of the live game.
+  #[wasm_bindgen]
+  pub fn score(&self) -> usize
+  {
+    self.0.game().score()
+  }
+
+  /// Amount of lives left in the live game.
+  #[wasm_bindgen]
+  pub fn lives(&self) -> usize
+  {
+    self.0.game().lives()
+  }
+
+  /// The commits of both games side by side.
+  #[wasm_bindgen]
+  pub fn diff(&self) -> Vec<GhostStepDiff>
+  {
+    self.0.diff().into_iter().map(GhostStepDiff).collect()
+  }
+
+  /// The index of the first commit after which the games had different amounts of lives left.
+  #[wasm_bindgen]
+  pub fn livesDivergedAt(&self) -> Option<usize>
+  {
+    self.0.lives_diverged_at()
+  }
+
+  #[wasm_bindgen]
+  pub fn intoGameOver(self) -> web_api::GameOver
+  {
+    web_api::GameOver::from(self.0.into_game())
+  }
+}
+
+/// The outcome of a commit in a `GhostRace`.
+#[wasm_bindgen]
+pub struct GhostStep(game_over::Step);
+
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+impl GhostStep
+{
+  #[wasm_bindgen]
+  pub fn index(&self) -> usize
+  {
+    self.0.index
+  }
+
+  /// True if the live commit was correct.
+  #[wasm_bindgen]
+  pub fn correct(&self) -> bool
+  {
+    self.0.correct
+  }
+
+  /// True if the ghost was correct at the same index, undefined if the ghost was out of lives.
+  #[wasm_bindgen]
+  pub fn ghostCorrect(&self) -> Option<bool>
+  {
+    self.0.ghost_correct
+  }
+
+  /// True if the ghost was shown the same element.
+  #[wasm_bindgen]
+  pub fn sameElement(&self) -> bool
+  {
+    self.0.same_element
+  }
+
+  #[wasm_bindgen]
+  pub fn lives(&self) -> usize
+  {
+    self.0.lives
+  }
+
+  #[wasm_bindgen]
+  pub fn ghostLives(&self) -> usize
+  {
+    self.0.ghost_lives
+  }
+}
+
+/// The live commit and the ghost commit at the same index.
+#[wasm_bindgen]
+pub struct GhostStepDiff(game_over::StepDiff<Inner>);
+
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+impl GhostStepDiff
+{
+  #[wasm_bindgen]
+  pub fn index(&self) -> usize
+  {
+    self.0.index
+  }
+
+  #[wasm_bindgen]
+  pub fn live(&self) -> Option<web_api::Commit>
+  {
+    self.0.live.clone().map(web_api::Commit::from)
+  }
+
+  #[wasm_bindgen]
+  pub fn ghost(&self) -> Option<web_api::Commit>
+  {
+    self.0.ghost.clone().map(web_api::Commit::from)
+  }
+
+  #[wasm_bindgen]
+  pub fn lives(&self) -> usize
+  {
+    self.0.lives
+  }
+
+  #[wasm_bindgen]
+  pub fn ghostLives(&self) -> usize
+  {
+    self.0.ghost_lives
+  }
+
+  /// True if only one of the games made the commit, if the elements differ or if only one of them
+  /// was correct.
+  #[wasm_bindgen]
+  pub fn diverges(&self) -> bool
+  {
+    self.0.diverges()
+  }
+}
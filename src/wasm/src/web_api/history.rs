@@ -0,0 +1,167 @@
+use std::error::Error;
+
+use wasm_bindgen::prelude::{
+  wasm_bindgen,
+  JsValue,
+};
+
+use crate::{
+  history::{
+    self,
+    HistoryStore,
+    Record,
+  },
+  web_api::EncodedGameOver,
+};
+
+// Every access can throw, e.g. when the quota is exceeded or the storage is disabled.
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(catch, js_namespace = localStorage, js_name = getItem)]
+  fn local_storage_get_item(key: &str) -> Result<Option<String>, JsValue>;
+
+  #[wasm_bindgen(catch, js_namespace = localStorage, js_name = setItem)]
+  fn local_storage_set_item(key: &str, value: &str) -> Result<(), JsValue>;
+
+  #[wasm_bindgen(catch, js_namespace = localStorage, js_name = removeItem)]
+  fn local_storage_remove_item(key: &str) -> Result<(), JsValue>;
+}
+
+/// An exception thrown by `localStorage` as an error.
+fn storage_error(e: JsValue) -> Box<dyn Error>
+{
+  format!(
+    "localStorage failed: {}",
+    e.as_string().unwrap_or_else(|| format!("{:?}", e))
+  )
+  .into()
+}
+
+// -------------------------------------------------------------------------------------------------
+// LocalStorageStore
+// -------------------------------------------------------------------------------------------------
+
+/// A `HistoryStore` that keeps the records as JSON-lines in a single item of the browsers
+/// `localStorage`.
+struct LocalStorageStore(String);
+
+impl HistoryStore for LocalStorageStore
+{
+  fn push(&mut self, record: Record) -> Result<(), Box<dyn Error>>
+  {
+    let mut s = local_storage_get_item(&self.0)
+      .map_err(storage_error)?
+      .unwrap_or_default();
+    s += &serde_json::to_string(&record)?;
+    s.push('\n');
+    local_storage_set_item(&self.0, &s).map_err(storage_error)
+  }
+
+  fn records(&self) -> Result<Vec<Record>, Box<dyn Error>>
+  {
+    local_storage_get_item(&self.0)
+      .map_err(storage_error)?
+      .unwrap_or_default()
+      .lines()
+      .filter(|x| !x.trim().is_empty())
+      .map(|x| Ok(serde_json::from_str(x)?))
+      .collect()
+  }
+
+  fn clear(&mut self) -> Result<(), Box<dyn Error>>
+  {
+    local_storage_remove_item(&self.0).map_err(storage_error)
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// History
+// -------------------------------------------------------------------------------------------------
+
+/// The games played by a player, stored in `localStorage`.
+#[wasm_bindgen]
+pub struct History(LocalStorageStore);
+
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+impl History
+{
+  /// A history stored under `key` in `localStorage`.
+  #[wasm_bindgen(constructor)]
+  pub fn new(key: String) -> History
+  {
+    History(LocalStorageStore(key))
+  }
+
+  /// Adds a game played at `timestamp`, in milliseconds since the unix epoch.
+  #[wasm_bindgen]
+  pub fn push(&mut self, encoded: &EncodedGameOver, timestamp: f64) -> Result<(), String>
+  {
+    self
+      .0
+      .push(Record::new(timestamp as u64, encoded.inner().clone()))
+      .map_err(|e| format!("{}", e))
+  }
+
+  /// The stored records.
+  #[wasm_bindgen]
+  pub fn records(&self) -> Result<Vec<HistoryRecord>, String>
+  {
+    Ok(
+      self
+        .0
+        .records()
+        .map_err(|e| format!("{}", e))?
+        .into_iter()
+        .map(HistoryRecord)
+        .collect(),
+    )
+  }
+
+  /// The records as JSON-lines.
+  #[wasm_bindgen]
+  pub fn exportJSONLines(&self) -> Result<String, String>
+  {
+    self.0.export().map_err(|e| format!("{}", e))
+  }
+
+  /// Adds the records of JSON-lines and returns the amount of added records.
+  #[wasm_bindgen]
+  pub fn importJSONLines(&mut self, jsonl: String) -> Result<usize, String>
+  {
+    self.0.import(&jsonl).map_err(|e| format!("{}", e))
+  }
+
+  /// Removes every record.
+  #[wasm_bindgen]
+  pub fn clear(&mut self) -> Result<(), String>
+  {
+    self.0.clear().map_err(|e| format!("{}", e))
+  }
+}
+
+// -------------------------------------------------------------------------------------------------
+// HistoryRecord
+// -------------------------------------------------------------------------------------------------
+
+/// A game in a `History`.
+#[wasm_bindgen]
+pub struct HistoryRecord(history::Record);
+
+#[wasm_bindgen]
+impl HistoryRecord
+{
+  /// Milliseconds since the unix epoch.
+  #[wasm_bindgen]
+  pub fn timestamp(&self) -> f64
+  {
+    self.0.timestamp() as f64
+  }
+
+  /// The encoded game.
+  #[wasm_bindgen]
+  pub fn encoded(&self) -> EncodedGameOver
+  {
+    EncodedGameOver::from(self.0.game().clone())
+  }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+  error::Error,
+  fs,
+  path::Path,
+};
+
+use serde_json::Value;
+
+use crate::coder::UnseenSetID;
+
+/// Parses a word list in the format of `public/dictionary/*/words.json`. The list is either an
+/// array of words or an object mapping indices to words, in which case the words are ordered by
+/// index.
+pub fn parse(json: &str) -> Result<Vec<String>, Box<dyn Error>>
+{
+  match serde_json::from_str(json)? {
+    Value::Array(words) => words.into_iter().map(|x| word(x)).collect(),
+
+    Value::Object(words) => {
+      let mut words = words
+        .into_iter()
+        .map(|(i, x)| Ok((i.parse::<usize>()?, word(x)?)))
+        .collect::<Result<Vec<(usize, String)>, Box<dyn Error>>>()?;
+      words.sort_by_key(|(i, _)| *i);
+      Ok(words.into_iter().map(|(_, x)| x).collect())
+    }
+
+    _ => Err("expected an array or an object of words".into()),
+  }
+}
+
+fn word(value: Value) -> Result<String, Box<dyn Error>>
+{
+  match value {
+    Value::String(s) => Ok(s),
+    _ => Err("expected word to be a string".into()),
+  }
+}
+
+/// Loads a word list from a file.
+pub fn load(path: &Path) -> Result<Vec<String>, Box<dyn Error>>
+{
+  parse(&fs::read_to_string(path)?)
+}
+
+/// Loads the word list of `unseen_set_id` from a directory laid out like `public/dictionary`.
+pub fn load_unseen_set(
+  dictionary: &Path,
+  unseen_set_id: &UnseenSetID,
+) -> Result<Vec<String>, Box<dyn Error>>
+{
+  let directory = unseen_set_id
+    .directory()
+    .ok_or_else(|| format!("{:?} has no word list", unseen_set_id))?;
+  load(&dictionary.join(directory).join("words.json"))
+}
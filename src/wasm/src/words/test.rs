@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn parse_array()
+{
+  assert_eq!(
+    parse(r#"["abaisse", "abandon", "abat-jour"]"#).unwrap(),
+    vec!["abaisse", "abandon", "abat-jour"]
+  );
+}
+
+#[test]
+fn parse_object_is_ordered_by_index()
+{
+  assert_eq!(
+    parse(r#"{"0":"je","2":"est","10":"il","1":"de"}"#).unwrap(),
+    vec!["je", "de", "est", "il"]
+  );
+}
+
+#[test]
+fn parse_invalid()
+{
+  assert!(parse(r#""je""#).is_err());
+  assert!(parse(r#"[1, 2]"#).is_err());
+  assert!(parse(r#"{"a":"je"}"#).is_err());
+}
+
+#[test]
+fn load_unseen_set_from_public_dictionary()
+{
+  let dictionary = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../public/dictionary");
+  let words = load_unseen_set(&dictionary, &UnseenSetID::Top999WiktionaryFr).unwrap();
+  assert_eq!(&words[..3], &["je", "de", "est"]);
+  assert!(load_unseen_set(&dictionary, &UnseenSetID::Unspecified).is_err());
+}